@@ -0,0 +1,238 @@
+//! `boxy`: draws text in a box from the shell.
+//!
+//! Text comes from `--segment` flags, or from stdin when none are given, with lines
+//! holding only `---` separating segments:
+//!
+//! ```text
+//! echo "Deploy done" | boxy --type rounded --color '#00ff00' --title Deploy
+//! printf 'Build\n---\n412 tests passed\n' | boxy --text-align center
+//! boxy -s "First segment" -s "Second segment" --width 40
+//! ```
+//!
+//! `boxy render` draws a box described by a JSON or TOML layout file instead, and
+//! `boxy run` the output of a command; see the `render` and `run` modules.
+//!
+//! Built with the `cli` cargo feature.
+
+mod render;
+mod run;
+
+use std::io::{self, IsTerminal, Read, Write};
+use std::process::ExitCode;
+
+use boxy_cli::prelude::*;
+use boxy_cli::term::{Detect, TerminalSize};
+use clap::builder::{PossibleValuesParser, TypedValueParser};
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use hex_color::HexColor;
+
+/// Draws text in a box
+#[derive(Debug, Parser)]
+#[command(name = "boxy", version, args_conflicts_with_subcommands = true)]
+struct Cli {
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    #[command(flatten)]
+    draw: DrawArgs,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Draws a box described by a JSON or TOML layout file
+    Render(render::RenderArgs),
+    /// Runs a command and draws its output, exit status and duration in a box
+    Run(run::RunArgs),
+}
+
+/// Flags for drawing text from the command line or stdin
+#[derive(Debug, Args)]
+struct DrawArgs {
+    /// Border style
+    #[arg(short = 't', long = "type", default_value = "single", value_parser = box_type_parser())]
+    box_type: BoxType,
+
+    /// Border color, as a hex color
+    #[arg(short, long, default_value = "#ffffff", value_parser = parse_color)]
+    color: String,
+
+    /// Text color, as a hex color
+    #[arg(long, default_value = "#ffffff", value_parser = parse_color)]
+    text_color: String,
+
+    /// Title color, as a hex color [default: the text color]
+    #[arg(long, value_parser = parse_color)]
+    title_color: Option<String>,
+
+    /// Centered title, drawn as the first segment
+    #[arg(long)]
+    title: Option<String>,
+
+    /// Position of the box in the terminal
+    #[arg(short, long, value_enum, default_value_t = Align::Left)]
+    align: Align,
+
+    /// Alignment of the text within each segment
+    #[arg(long, value_enum, default_value_t = Align::Left)]
+    text_align: Align,
+
+    /// Padding between the border and the text: N, V,H or TOP,LEFT,DOWN,RIGHT
+    #[arg(short, long, default_value = "1", value_parser = parse_pad)]
+    padding: BoxPad,
+
+    /// Space between the terminal edge and the box, in the same forms as --padding
+    #[arg(short, long, default_value = "0", value_parser = parse_pad)]
+    margin: BoxPad,
+
+    /// Fixed box width in columns, borders included [default: fit the terminal]
+    #[arg(short, long)]
+    width: Option<usize>,
+
+    /// Width to render into [default: $COLUMNS, the terminal width, or 80]
+    #[arg(long)]
+    term_width: Option<usize>,
+
+    /// A segment of text; repeat for more segments. Without any, stdin is read
+    #[arg(short, long = "segment", value_name = "TEXT")]
+    segments: Vec<String>,
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum Align {
+    Left,
+    Center,
+    Right,
+}
+
+impl From<Align> for BoxAlign {
+    fn from(align: Align) -> Self {
+        match align {
+            Align::Left => BoxAlign::Left,
+            Align::Center => BoxAlign::Center,
+            Align::Right => BoxAlign::Right,
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    match &cli.command {
+        Some(Command::Render(args)) => render::run(args),
+        Some(Command::Run(args)) => run::run(args),
+        None => draw(&cli.draw),
+    }
+}
+
+fn draw(cli: &DrawArgs) -> ExitCode {
+    let mut input = String::new();
+    let segments = if !cli.segments.is_empty() {
+        cli.segments.iter().map(|s| split_lines(s)).collect()
+    } else if cli.title.is_some() && io::stdin().is_terminal() {
+        // a title on its own is enough; don't wait for typed input
+        Vec::new()
+    } else {
+        if let Err(e) = io::stdin().read_to_string(&mut input) {
+            eprintln!("boxy: failed to read stdin: {}", e);
+            return ExitCode::FAILURE;
+        }
+        split_segments(&input)
+    };
+    let segments: Vec<Vec<&str>> = segments.into_iter().filter(|s| !s.is_empty()).collect();
+    if segments.is_empty() && cli.title.is_none() {
+        eprintln!("boxy: nothing to draw; pass --segment, --title or text on stdin");
+        return ExitCode::from(2);
+    }
+
+    let mut builder = Boxy::builder()
+        .box_type(cli.box_type.clone())
+        .color(&cli.color)
+        .align(cli.align.into())
+        .padding(cli.margin, cli.padding);
+    if let Some(width) = cli.width {
+        builder = builder.width(width);
+    }
+    if let Some(title) = &cli.title {
+        let color = cli.title_color.as_deref().unwrap_or(&cli.text_color);
+        builder = builder.add_segment(title, color, BoxAlign::Center);
+    }
+    for lines in &segments {
+        builder = builder.add_segment(lines[0], &cli.text_color, cli.text_align.into());
+        for line in &lines[1..] {
+            builder = builder.add_line(line, &cli.text_color);
+        }
+    }
+    match builder.try_build() {
+        Ok(boxy) => print(&boxy, cli.term_width),
+        Err(e) => {
+            eprintln!("boxy: {}", e);
+            ExitCode::from(2)
+        }
+    }
+}
+
+// Writes the box to stdout at `term_width`, or at the width of `COLUMNS` or the terminal
+// (80 without either)
+fn print(boxy: &Boxy, term_width: Option<usize>) -> ExitCode {
+    let term_width = term_width
+        .or_else(|| Detect::new().size().map(|size| size.cols))
+        .unwrap_or(80);
+    let mut out = io::stdout().lock();
+    match boxy
+        .write_to(&mut out, term_width)
+        .and_then(|_| out.flush())
+    {
+        Ok(()) => ExitCode::SUCCESS,
+        // `boxy ... | head` closing the pipe early isn't an error
+        Err(e) if e.kind() == io::ErrorKind::BrokenPipe => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("boxy: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+// The lines of one segment, without the trailing carriage returns of CRLF input
+fn split_lines(text: &str) -> Vec<&str> {
+    text.lines()
+        .map(|line| line.trim_end_matches('\r'))
+        .collect()
+}
+
+// Segments of stdin input, separated by lines holding only `---`
+fn split_segments(input: &str) -> Vec<Vec<&str>> {
+    let mut segments = vec![Vec::new()];
+    for line in split_lines(input) {
+        if line.trim() == "---" {
+            segments.push(Vec::new());
+        } else if let Some(current) = segments.last_mut() {
+            current.push(line);
+        }
+    }
+    segments
+}
+
+// Parses `--type`: one of the built-in style names, listed in `--help`
+fn box_type_parser() -> impl TypedValueParser<Value = BoxType> {
+    PossibleValuesParser::new(BoxType::NAMES)
+        .map(|name| BoxType::from_name(&name).expect("NAMES only holds built-in styles"))
+}
+
+fn parse_color(value: &str) -> Result<String, String> {
+    HexColor::parse(value)
+        .map(|_| value.to_string())
+        .map_err(|e| format!("{} (expected a hex color such as '#00ff00')", e))
+}
+
+fn parse_pad(value: &str) -> Result<BoxPad, String> {
+    let parts = value
+        .split(',')
+        .map(|part| part.trim().parse::<usize>())
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| e.to_string())?;
+    match parts[..] {
+        [all] => Ok(BoxPad::uniform(all)),
+        [vertical, horizontal] => Ok(BoxPad::vh(vertical, horizontal)),
+        [top, left, down, right] => Ok(BoxPad::from_tldr(top, left, down, right)),
+        _ => Err("expected N, V,H or TOP,LEFT,DOWN,RIGHT".to_string()),
+    }
+}
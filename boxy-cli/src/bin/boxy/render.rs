@@ -0,0 +1,335 @@
+//! `boxy render`: draws a box described by a layout file.
+//!
+//! A layout is a [`BoxySpec`] in JSON or TOML, the format `Boxy` itself serializes to, so
+//! generators in any language can describe segments, columns with ratios, per-line colors
+//! and border styles:
+//!
+//! ```toml
+//! type = "rounded"
+//! color = "#00ffff"
+//!
+//! [[segments]]
+//! content = { single = ["Nightly build"] }
+//! align = "center"
+//!
+//! [[segments]]
+//! content = { columnar = [["unit", "docs"], ["passed", "failed"]] }
+//! colors = { columnar = [["#ffffff", "#ffffff"], ["#00ff00", "#ff0000"]] }
+//! ratios = [2, 1]
+//! ```
+//!
+//! Errors name the line and column of the offending value, whether the file doesn't
+//! parse, has an unknown key, or holds a value [`Boxy::from_spec`] rejects.
+
+use std::fs;
+use std::io::{self, Read};
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use boxy_cli::prelude::*;
+use clap::{Args, ValueEnum};
+use toml::Spanned;
+use toml::de::{DeTable, DeValue};
+
+#[derive(Debug, Args)]
+pub struct RenderArgs {
+    /// The layout file, or - to read it from stdin
+    file: PathBuf,
+
+    /// Layout format [default: toml for .toml files, json otherwise]
+    #[arg(long, value_enum)]
+    format: Option<Format>,
+
+    /// Width to render into [default: $COLUMNS, the terminal width, or 80]
+    #[arg(long)]
+    term_width: Option<usize>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Format {
+    Json,
+    Toml,
+}
+
+pub fn run(args: &RenderArgs) -> ExitCode {
+    let from_stdin = args.file.as_os_str() == "-";
+    let name = if from_stdin {
+        "<stdin>".to_string()
+    } else {
+        args.file.display().to_string()
+    };
+    let mut source = String::new();
+    let read = if from_stdin {
+        io::stdin().read_to_string(&mut source).map(|_| ())
+    } else {
+        fs::read_to_string(&args.file).map(|text| source = text)
+    };
+    if let Err(e) = read {
+        eprintln!("boxy: {}: {}", name, e);
+        return ExitCode::FAILURE;
+    }
+
+    let extension = args.file.extension().and_then(|ext| ext.to_str());
+    let format = args.format.unwrap_or(match extension {
+        Some("toml") if !from_stdin => Format::Toml,
+        _ => Format::Json,
+    });
+    match load(&source, format) {
+        Ok(boxy) => super::print(&boxy, args.term_width),
+        Err(e) => {
+            eprintln!("boxy: {}:{}:{}: {}", name, e.line, e.column, e.message);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+// An error in a layout, at a 1-based line and column of its source
+struct LayoutError {
+    line: usize,
+    column: usize,
+    message: String,
+}
+
+impl LayoutError {
+    fn at(source: &str, offset: usize, message: impl Into<String>) -> Self {
+        let before = source.get(..offset).unwrap_or(source);
+        Self {
+            line: before.matches('\n').count() + 1,
+            column: before.rsplit('\n').next().unwrap_or("").chars().count() + 1,
+            message: message.into(),
+        }
+    }
+}
+
+fn load(source: &str, format: Format) -> Result<Boxy, LayoutError> {
+    let spec: BoxySpec = match format {
+        Format::Json => serde_json::from_str(source).map_err(|e| {
+            // the position goes in front, not at the end
+            let message = e.to_string();
+            let position = format!(" at line {} column {}", e.line(), e.column());
+            LayoutError {
+                line: e.line(),
+                column: e.column(),
+                message: message
+                    .strip_suffix(&position)
+                    .unwrap_or(&message)
+                    .to_string(),
+            }
+        })?,
+        Format::Toml => toml::from_str(source).map_err(|e| {
+            let offset = e.span().map_or(0, |span| span.start);
+            LayoutError::at(source, offset, e.message())
+        })?,
+    };
+    Boxy::from_spec(&spec).map_err(|e| {
+        let steps = steps(e.key());
+        let offset = match format {
+            Format::Json => locate_json(source, &steps),
+            Format::Toml => locate_toml(source, &steps),
+        };
+        LayoutError::at(source, offset, e.to_string())
+    })
+}
+
+enum Step<'a> {
+    Key(&'a str),
+    Index(usize),
+}
+
+// A `SpecError` key such as `segments[1].colors[0][2]`, as keys and indices
+fn steps(key: &str) -> Vec<Step<'_>> {
+    let mut steps = Vec::new();
+    for part in key.split('.') {
+        let (name, indices) = part.split_once('[').unwrap_or((part, ""));
+        if !name.is_empty() {
+            steps.push(Step::Key(name));
+        }
+        for index in indices.split(['[', ']']) {
+            if let Ok(index) = index.parse() {
+                steps.push(Step::Index(index));
+            }
+        }
+    }
+    steps
+}
+
+// Byte offset of the value at `steps` in a TOML layout, or of the deepest part of the
+// path that was found
+fn locate_toml(source: &str, steps: &[Step<'_>]) -> usize {
+    let Ok(root) = DeTable::parse(source) else {
+        return 0;
+    };
+    let root = Spanned::new(root.span(), DeValue::Table(root.into_inner()));
+    let mut current = &root;
+    for step in steps {
+        match toml_child(current, step) {
+            Some(child) => current = child,
+            None => break,
+        }
+    }
+    current.span().start
+}
+
+fn toml_child<'a, 'i>(
+    value: &'a Spanned<DeValue<'i>>,
+    step: &Step<'_>,
+) -> Option<&'a Spanned<DeValue<'i>>> {
+    match (value.get_ref(), step) {
+        (DeValue::Table(table), Step::Key(key)) => table
+            .iter()
+            .find(|(k, _)| k.get_ref().as_ref() == *key)
+            .map(|(_, v)| v)
+            .or_else(|| toml_child(only_value(table.iter().map(|(_, v)| v))?, step)),
+        (DeValue::Array(items), Step::Index(i)) => items.get(*i),
+        // an enum tag such as `single = [...]` sits between a key and its contents
+        (DeValue::Table(table), Step::Index(_)) => {
+            toml_child(only_value(table.iter().map(|(_, v)| v))?, step)
+        }
+        _ => None,
+    }
+}
+
+// Byte offset of the value at `steps` in a JSON layout, or of the deepest part of the
+// path that was found
+fn locate_json(source: &str, steps: &[Step<'_>]) -> usize {
+    let mut scanner = Scanner {
+        src: source,
+        pos: 0,
+    };
+    scanner.whitespace();
+    let mut at = scanner.pos;
+    for step in steps {
+        match json_child(&mut scanner, at, step) {
+            Some(child) => at = child,
+            None => break,
+        }
+    }
+    at
+}
+
+fn json_child(scanner: &mut Scanner<'_>, at: usize, step: &Step<'_>) -> Option<usize> {
+    scanner.pos = at;
+    match (scanner.peek()?, step) {
+        (b'{', Step::Key(key)) => {
+            let members = scanner.members();
+            match members.iter().find(|(k, _)| k == key) {
+                Some((_, value)) => Some(*value),
+                None => json_child(scanner, only_value(members.iter().map(|(_, v)| *v))?, step),
+            }
+        }
+        (b'[', Step::Index(i)) => scanner.elements().get(*i).copied(),
+        // an enum tag such as `{"single": [...]}` sits between a key and its contents
+        (b'{', Step::Index(_)) => {
+            let members = scanner.members();
+            json_child(scanner, only_value(members.iter().map(|(_, v)| *v))?, step)
+        }
+        _ => None,
+    }
+}
+
+fn only_value<T>(mut values: impl Iterator<Item = T>) -> Option<T> {
+    match (values.next(), values.next()) {
+        (Some(value), None) => Some(value),
+        _ => None,
+    }
+}
+
+// Just enough of a JSON reader to find where values start. It only runs on layouts
+// serde_json already parsed.
+struct Scanner<'a> {
+    src: &'a str,
+    pos: usize,
+}
+
+impl<'a> Scanner<'a> {
+    fn peek(&self) -> Option<u8> {
+        self.src.as_bytes().get(self.pos).copied()
+    }
+
+    fn whitespace(&mut self) {
+        while matches!(self.peek(), Some(b' ' | b'\t' | b'\n' | b'\r')) {
+            self.pos += 1;
+        }
+    }
+
+    // The raw contents of the string at `pos`, leaving `pos` after its closing quote
+    fn string(&mut self) -> &'a str {
+        let start = self.pos + 1;
+        self.pos = start;
+        while let Some(c) = self.peek() {
+            match c {
+                b'\\' => self.pos += 2,
+                b'"' => break,
+                _ => self.pos += 1,
+            }
+        }
+        let text = self.src.get(start..self.pos).unwrap_or("");
+        self.pos += 1;
+        text
+    }
+
+    // The keys and value offsets of the object at `pos`, leaving `pos` at its `}`
+    fn members(&mut self) -> Vec<(&'a str, usize)> {
+        let mut members = Vec::new();
+        self.pos += 1;
+        loop {
+            self.whitespace();
+            match self.peek() {
+                Some(b'"') => {
+                    let key = self.string();
+                    self.whitespace();
+                    if self.peek() == Some(b':') {
+                        self.pos += 1;
+                    }
+                    self.whitespace();
+                    members.push((key, self.pos));
+                    self.skip();
+                }
+                Some(b',') => self.pos += 1,
+                _ => return members,
+            }
+        }
+    }
+
+    // The offsets of the elements of the array at `pos`, leaving `pos` at its `]`
+    fn elements(&mut self) -> Vec<usize> {
+        let mut elements = Vec::new();
+        self.pos += 1;
+        loop {
+            self.whitespace();
+            match self.peek() {
+                Some(b']') | None => return elements,
+                Some(b',') => self.pos += 1,
+                Some(_) => {
+                    elements.push(self.pos);
+                    self.skip();
+                }
+            }
+        }
+    }
+
+    fn skip(&mut self) {
+        match self.peek() {
+            Some(b'"') => {
+                self.string();
+            }
+            Some(b'{') => {
+                self.members();
+                self.pos += 1;
+            }
+            Some(b'[') => {
+                self.elements();
+                self.pos += 1;
+            }
+            _ => {
+                self.pos += 1;
+                while !matches!(
+                    self.peek(),
+                    None | Some(b',' | b'}' | b']' | b' ' | b'\t' | b'\n' | b'\r')
+                ) {
+                    self.pos += 1;
+                }
+            }
+        }
+    }
+}
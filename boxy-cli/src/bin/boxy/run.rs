@@ -0,0 +1,338 @@
+//! `boxy run`: runs a command and draws its output in a box.
+//!
+//! ```text
+//! boxy run -- cargo test
+//! boxy run --split --tail 20 -- ./deploy.sh staging
+//! ```
+//!
+//! The title is the command line and the footer its exit status and duration. The border
+//! is green when the command succeeds and red when it fails, and `boxy` exits with the
+//! command's exit code. stdout and stderr are interleaved line by line as they arrive,
+//! with stderr in a lighter red, or drawn in separate segments with `--split`.
+
+use std::io::{BufRead, BufReader, Read};
+use std::process::{Command, ExitCode, ExitStatus, Stdio};
+use std::sync::mpsc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use boxy_cli::prelude::*;
+use clap::Args;
+
+use super::{box_type_parser, parse_color};
+
+const STDOUT_COLOR: &str = "#ffffff";
+const STDERR_COLOR: &str = "#ff8787";
+const NOTE_COLOR: &str = "#888888";
+
+#[derive(Debug, Args)]
+pub struct RunArgs {
+    /// Border style
+    #[arg(short = 't', long = "type", default_value = "single", value_parser = box_type_parser())]
+    box_type: BoxType,
+
+    /// Border color when the command succeeds
+    #[arg(long, default_value = "#00ff00", value_parser = parse_color)]
+    success_color: String,
+
+    /// Border color when the command fails
+    #[arg(long, default_value = "#ff0000", value_parser = parse_color)]
+    failure_color: String,
+
+    /// Draw stdout and stderr in separate segments
+    #[arg(long)]
+    split: bool,
+
+    /// Keep only the last N lines of output (of each stream with --split)
+    #[arg(long, value_name = "N")]
+    tail: Option<usize>,
+
+    /// Fixed box width in columns, borders included [default: fit the terminal]
+    #[arg(short, long)]
+    width: Option<usize>,
+
+    /// Width to render into [default: $COLUMNS, the terminal width, or 80]
+    #[arg(long)]
+    term_width: Option<usize>,
+
+    /// The command to run, and its arguments
+    #[arg(required = true, trailing_var_arg = true, allow_hyphen_values = true)]
+    command: Vec<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Stream {
+    Stdout,
+    Stderr,
+}
+
+pub fn run(args: &RunArgs) -> ExitCode {
+    // a box that can't be drawn, like one narrower than --width allows, is reported
+    // before the command runs
+    if let Err(e) = output_box(args, &[], ExitStatus::default(), Duration::ZERO) {
+        eprintln!("boxy: {}", e);
+        return ExitCode::from(2);
+    }
+
+    let start = Instant::now();
+    let mut child = match Command::new(&args.command[0])
+        .args(&args.command[1..])
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+    {
+        Ok(child) => child,
+        Err(e) => {
+            eprintln!("boxy: failed to run {}: {}", args.command[0], e);
+            // the shell's code for a command that can't be found or executed
+            return ExitCode::from(127);
+        }
+    };
+
+    // read both pipes at once, so neither fills up and blocks the command
+    let (tx, rx) = mpsc::channel();
+    let readers = [
+        child
+            .stdout
+            .take()
+            .map(|out| forward(out, Stream::Stdout, tx.clone())),
+        child
+            .stderr
+            .take()
+            .map(|err| forward(err, Stream::Stderr, tx.clone())),
+    ];
+    drop(tx);
+    let output: Vec<(Stream, String)> = rx.iter().collect();
+    for reader in readers.into_iter().flatten() {
+        let _ = reader.join();
+    }
+    let status = match child.wait() {
+        Ok(status) => status,
+        Err(e) => {
+            eprintln!("boxy: failed to wait for {}: {}", args.command[0], e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let boxy = match output_box(args, &output, status, start.elapsed()) {
+        Ok(boxy) => boxy,
+        Err(e) => {
+            eprintln!("boxy: {}", e);
+            return ExitCode::from(2);
+        }
+    };
+    match super::print(&boxy, args.term_width) {
+        code if code != ExitCode::SUCCESS => code,
+        _ => exit_code(status),
+    }
+}
+
+// Sends each line the stream produces, lossily decoded, until it closes
+fn forward(
+    stream: impl Read + Send + 'static,
+    kind: Stream,
+    tx: mpsc::Sender<(Stream, String)>,
+) -> thread::JoinHandle<()> {
+    thread::spawn(move || {
+        let mut reader = BufReader::new(stream);
+        let mut buf = Vec::new();
+        while let Ok(n) = reader.read_until(b'\n', &mut buf) {
+            if n == 0 {
+                break;
+            }
+            let line = String::from_utf8_lossy(&buf);
+            if tx.send((kind, clean(&line))).is_err() {
+                break;
+            }
+            buf.clear();
+        }
+    })
+}
+
+fn output_box(
+    args: &RunArgs,
+    output: &[(Stream, String)],
+    status: ExitStatus,
+    elapsed: Duration,
+) -> Result<Boxy, BoxyError> {
+    let color = match status.success() {
+        true => &args.success_color,
+        false => &args.failure_color,
+    };
+
+    // every line is a segment of its own with no divider before it, so lines stay
+    // together instead of being spaced out like the lines of one segment
+    let mut rows = vec![Row::new(
+        clean(&format!("$ {}", command_line(&args.command))),
+        "#ffffff",
+    )];
+    rows[0].align = BoxAlign::Center;
+    if args.split {
+        for (stream, label) in [(Stream::Stdout, "stdout"), (Stream::Stderr, "stderr")] {
+            let lines: Vec<(Stream, String)> = output
+                .iter()
+                .filter(|(s, _)| *s == stream)
+                .cloned()
+                .collect();
+            let mut section = output_rows(&lines, args.tail);
+            if let Some(first) = section.first_mut() {
+                first.divider = Divider::Labeled(label.to_string());
+            }
+            rows.extend(section);
+        }
+    } else {
+        let mut section = output_rows(output, args.tail);
+        if let Some(first) = section.first_mut() {
+            first.divider = Divider::Rule;
+        }
+        rows.extend(section);
+    }
+    rows.push(Row {
+        text: format!("{} after {}", status_text(status), duration(elapsed)),
+        color: color.clone(),
+        align: BoxAlign::Right,
+        divider: Divider::Rule,
+    });
+
+    let mut builder = Boxy::builder()
+        .box_type(args.box_type.clone())
+        .color(color)
+        .align(BoxAlign::Left);
+    if let Some(width) = args.width {
+        builder = builder.width(width);
+    }
+    for (i, row) in rows.into_iter().enumerate() {
+        if i > 0 {
+            builder = builder.segment_divider(i - 1, row.divider);
+        }
+        builder = builder.add_segment(&row.text, &row.color, row.align);
+    }
+    builder.try_build()
+}
+
+// One line of the box, and what separates it from the line above
+struct Row {
+    text: String,
+    color: String,
+    align: BoxAlign,
+    divider: Divider,
+}
+
+impl Row {
+    fn new(text: String, color: &str) -> Self {
+        Self {
+            text,
+            color: color.to_string(),
+            align: BoxAlign::Left,
+            divider: Divider::None,
+        }
+    }
+}
+
+// The output lines, without trailing blank ones, cut down to the last `tail`
+fn output_rows(lines: &[(Stream, String)], tail: Option<usize>) -> Vec<Row> {
+    let end = lines
+        .iter()
+        .rposition(|(_, line)| !line.trim().is_empty())
+        .map_or(0, |i| i + 1);
+    let lines = &lines[..end];
+    let start = tail.map_or(0, |tail| lines.len().saturating_sub(tail));
+
+    let mut rows = Vec::new();
+    if start > 0 {
+        let noun = if start == 1 { "line" } else { "lines" };
+        rows.push(Row::new(
+            format!("... {} earlier {} hidden", start, noun),
+            NOTE_COLOR,
+        ));
+    }
+    for (stream, line) in &lines[start..] {
+        let color = match stream {
+            Stream::Stdout => STDOUT_COLOR,
+            Stream::Stderr => STDERR_COLOR,
+        };
+        rows.push(Row::new(line.clone(), color));
+    }
+    rows
+}
+
+// A line of output as plain text: no line ending or escape sequences, tabs as spaces
+fn clean(line: &str) -> String {
+    let line = line.trim_end_matches(['\n', '\r']);
+    let mut out = String::with_capacity(line.len());
+    let mut chars = line.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            // CSI sequences such as colors end at the first letter
+            '\x1b' => {
+                if chars.next() == Some('[') {
+                    for c in chars.by_ref() {
+                        if c.is_ascii_alphabetic() {
+                            break;
+                        }
+                    }
+                }
+            }
+            '\t' => out.push_str("    "),
+            c if c.is_control() => {}
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+// The command as it would be typed, quoting arguments the shell would split
+fn command_line(command: &[String]) -> String {
+    command
+        .iter()
+        .map(|arg| {
+            let plain = !arg.is_empty()
+                && arg
+                    .chars()
+                    .all(|c| c.is_alphanumeric() || "-_./=:,+@%".contains(c));
+            match plain {
+                true => arg.clone(),
+                false => format!("'{}'", arg.replace('\'', "'\\''")),
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn status_text(status: ExitStatus) -> String {
+    if let Some(code) = status.code() {
+        return format!("exit {}", code);
+    }
+    #[cfg(unix)]
+    {
+        use std::os::unix::process::ExitStatusExt;
+        if let Some(signal) = status.signal() {
+            return format!("killed by signal {}", signal);
+        }
+    }
+    status.to_string()
+}
+
+// The command's exit code, or 128 + the signal that killed it, as shells report it
+fn exit_code(status: ExitStatus) -> ExitCode {
+    if let Some(code) = status.code() {
+        return ExitCode::from(code as u8);
+    }
+    #[cfg(unix)]
+    {
+        use std::os::unix::process::ExitStatusExt;
+        if let Some(signal) = status.signal() {
+            return ExitCode::from(128u8.wrapping_add(signal as u8));
+        }
+    }
+    ExitCode::FAILURE
+}
+
+fn duration(elapsed: Duration) -> String {
+    let secs = elapsed.as_secs_f64();
+    if secs < 60.0 {
+        format!("{:.2}s", secs)
+    } else {
+        format!("{}m{:02}s", elapsed.as_secs() / 60, elapsed.as_secs() % 60)
+    }
+}
@@ -2,10 +2,19 @@
 
 use crate::constructs::SegColor;
 use crate::constructs::*;
+use crate::error::{
+    BoxyError, check_color, check_column, check_divider, check_paint, check_ratios, check_type,
+    check_width, last_segment,
+};
+#[cfg(any(feature = "html", feature = "svg"))]
+use crate::paint::Run;
+use crate::paint::{BorderPaint, ColorMode, Layout, Line, Paint, Shadow, ShadowPaint};
+#[cfg(feature = "serde")]
+use crate::spec::{BoxySpec, SegmentColors, SegmentSpec};
 use crate::templates::*;
-use colored::ColoredString;
-use colored::{Color, Colorize};
-use std::fmt::Write;
+use crate::term::{Detect, Provider, Size, TerminalSize};
+use colored::Color;
+use std::{fmt, io};
 use unicode_segmentation::UnicodeSegmentation;
 use unicode_width::UnicodeWidthStr;
 
@@ -30,6 +39,8 @@ pub struct Boxy {
     data: Vec<SegType>,
     sect_count: usize,
     box_col: Color,
+    paint: Paint,
+    shadow: Option<ShadowPaint>,
     colors: Vec<SegColor>,
     int_padding: BoxPad,
     ext_padding: BoxPad,
@@ -39,7 +50,13 @@ pub struct Boxy {
     fixed_height: usize,
     seg_cols_count: Vec<usize>,
     seg_cols_ratio: Vec<Vec<usize>>,
+    seg_dividers: Vec<Divider>,
     terminal_width_offset: i32,
+    ascii_fallback: AsciiFallback,
+    divider_style: Option<BoxType>,
+    color_mode: ColorMode,
+    non_tty_mode: NonTtyMode,
+    size_provider: Option<Provider>,
 }
 
 // Default struct values for the textbox
@@ -50,6 +67,8 @@ impl Default for Boxy {
             data: Vec::<SegType>::new(),
             sect_count: 0usize,
             box_col: SegColor::parse_hexcolor("#ffffff"),
+            paint: Paint::Solid,
+            shadow: None,
             colors: Vec::<SegColor>::new(),
             int_padding: BoxPad::new(),
             ext_padding: BoxPad::new(),
@@ -59,18 +78,86 @@ impl Default for Boxy {
             fixed_height: 0usize,
             seg_cols_ratio: Vec::<Vec<usize>>::new(),
             seg_cols_count: Vec::<usize>::new(),
+            seg_dividers: Vec::<Divider>::new(),
             terminal_width_offset: -20,
+            ascii_fallback: AsciiFallback::Never,
+            divider_style: None,
+            color_mode: ColorMode::Auto,
+            non_tty_mode: NonTtyMode::Auto,
+            size_provider: None,
         }
     }
 }
 
-const DEFAULT_PAD: BoxPad = BoxPad {
+pub(crate) const DEFAULT_PAD: BoxPad = BoxPad {
     top: 1,
     left: 1,
     down: 1,
     right: 1,
 };
 
+// Render width for `Display` when there is no terminal to measure
+pub(crate) const FALLBACK_WIDTH: usize = 80;
+
+// Where a box sits at one terminal width, and the glyphs it is drawn with
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Frame {
+    // columns between the left and right borders
+    disp_width: usize,
+    align_offset: usize,
+    // columns before the left border
+    margin: usize,
+    box_pieces: BorderSet,
+    divider_pieces: BorderSet,
+    // whether borders are drawn in ASCII
+    ascii: bool,
+}
+
+/// Renders the box, one line per row, without a trailing newline.
+///
+/// The formatter width picks the render width (`{:60}`); without one, the box is sized to
+/// the terminal (see [`Boxy::set_size_provider`]), or to 80 columns if its size can't be
+/// told (e.g. when output is piped).
+/// Unlike [`Boxy::display`], the box is drawn even then.
+///
+/// The alternate flag (`{:#}`) renders as [`Boxy::render_plain`]: without color escapes,
+/// and with the glyphs chosen whatever the locale.
+///
+/// # Examples
+///
+/// ```
+/// use boxy_cli::prelude::*;
+///
+/// let mut b = Boxy::new(BoxType::Single, "#00ffff");
+/// b.add_text_sgmt("Deployed", "#ffffff", BoxAlign::Center);
+///
+/// let s = format!("{:30}", b);
+/// assert_eq!(s.lines().count(), 3);
+/// assert_eq!(s.lines().collect::<Vec<_>>(), b.render(30));
+/// assert_eq!(format!("{:#30}", b).lines().collect::<Vec<_>>(), b.render_plain(30));
+/// println!("{}", b);
+/// ```
+impl fmt::Display for Boxy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let term_width = f
+            .width()
+            .unwrap_or_else(|| self.term_size().map_or(FALLBACK_WIDTH, |s| s.cols));
+        let (frame, mode) = if f.alternate() {
+            (self.plain_frame(term_width), ColorMode::Never)
+        } else {
+            (self.frame(term_width), self.color_mode)
+        };
+        let mut first = true;
+        self.for_each_line(frame, mode, |line| {
+            if !first {
+                f.write_str("\n")?;
+            }
+            first = false;
+            f.write_str(line)
+        })
+    }
+}
+
 impl Boxy {
     /// Creates a new instance of the `Boxy` struct with the specified border type and color.
     ///
@@ -144,6 +231,7 @@ impl Boxy {
         self.sect_count += 1;
         self.seg_cols_count.push(0);
         self.seg_cols_ratio.push(vec![1]);
+        self.seg_dividers.push(Divider::Rule);
     }
 
     /// Adds a new columnar segment to the text box, separated by a horizontal divider.
@@ -189,6 +277,7 @@ impl Boxy {
         self.sect_count += 1;
         self.seg_cols_count.push(column_count);
         self.seg_cols_ratio.push(vec![1; column_count]); // default to equal width
+        self.seg_dividers.push(Divider::Rule);
     }
 
     /// Adds a new text line to the segment with a specific index.
@@ -572,6 +661,234 @@ impl Boxy {
         self.type_enum = box_type;
     }
 
+    /// Sets when the border is drawn in plain ASCII instead of the chosen glyphs.
+    ///
+    /// Defaults to [`AsciiFallback::Never`]. With [`AsciiFallback::Auto`], the locale and
+    /// terminal are checked on every render (see [`term::supports_utf8`](crate::term::supports_utf8)).
+    ///
+    /// # Arguments
+    ///
+    /// * `fallback` - The [`AsciiFallback`] policy
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use boxy_cli::prelude::*;
+    ///
+    /// let mut b = Boxy::new(BoxType::Rounded, "#00ffff");
+    /// b.set_ascii_fallback(AsciiFallback::Always);
+    /// b.add_text_sgmt("Hello", "#ffffff", BoxAlign::Center);
+    /// assert!(b.render(20)[0].contains("+------"));
+    /// ```
+    pub fn set_ascii_fallback(&mut self, fallback: AsciiFallback) {
+        self.ascii_fallback = fallback;
+    }
+
+    /// Casts a drop shadow to the right of and below the box.
+    ///
+    /// The shadow counts towards the box's footprint: a box sized to the terminal shrinks
+    /// to make room for it, and centered boxes are centered with their shadow.
+    ///
+    /// # Arguments
+    ///
+    /// * `shadow` - The [`Shadow`] style, offset and color. An invalid hex color falls back to white with a stderr warning
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use boxy_cli::prelude::*;
+    ///
+    /// let mut b = Boxy::new(BoxType::Single, "#00ffff");
+    /// b.set_shadow(Shadow::new(ShadowStyle::Light));
+    /// b.add_text_sgmt("Splash!", "#ffffff", BoxAlign::Center);
+    /// let lines = b.render(30);
+    /// assert!(lines[1].ends_with("░░"));
+    /// assert_eq!(lines.len(), 4); // top, text, bottom, shadow
+    /// ```
+    pub fn set_shadow(&mut self, shadow: Shadow) {
+        self.shadow = Some(ShadowPaint::resolve(&shadow));
+    }
+
+    /// Sets whether output is colored, for every way of rendering the box.
+    ///
+    /// Defaults to [`ColorMode::Auto`], which leaves it to the `colored` crate's
+    /// environment and TTY detection. [`ColorMode::Never`] makes [`render`](Self::render),
+    /// [`write_to`](Self::write_to), [`fmt_into`](Self::fmt_into) and
+    /// [`display`](Self::display) emit plain text; [`ColorMode::Always`] forces
+    /// escapes, e.g. when piping into a pager that understands them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use boxy_cli::prelude::*;
+    ///
+    /// let mut b = Boxy::new(BoxType::Single, "#00ffff");
+    /// b.add_text_sgmt("Report", "#ffffff", BoxAlign::Center);
+    /// b.set_color_mode(ColorMode::Never);
+    ///
+    /// let mut golden = Vec::new();
+    /// b.write_to(&mut golden, 30)?;
+    /// assert!(!golden.contains(&0x1b));
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    pub fn set_color_mode(&mut self, mode: ColorMode) {
+        self.color_mode = mode;
+    }
+
+    /// Sets what [`display`](Self::display) prints when it can't tell the terminal size.
+    ///
+    /// Defaults to [`NonTtyMode::Auto`]: whatever the `BOXY_NON_TTY` environment variable
+    /// names, and only the text when it is unset, so piped output isn't cluttered with
+    /// borders. [`NonTtyMode::Plain`] and [`NonTtyMode::Full`] draw the box at 80 columns,
+    /// and [`NonTtyMode::Structured`] prints columnar segments as `key: value` lines.
+    ///
+    /// # Arguments
+    ///
+    /// * `mode` - The [`NonTtyMode`] to use
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use boxy_cli::prelude::*;
+    ///
+    /// let mut b = Boxy::new(BoxType::Single, "#00ffff");
+    /// b.add_col_text_sgmt(BoxAlign::Left, 2);
+    /// b.add_col_text_line("unit", "#ffffff", &0usize);
+    /// b.add_col_text_line("passed", "#00ff00", &1usize);
+    /// // `unit: passed` when piped
+    /// b.set_non_tty_mode(NonTtyMode::Structured);
+    /// b.display();
+    /// ```
+    pub fn set_non_tty_mode(&mut self, mode: NonTtyMode) {
+        self.non_tty_mode = mode;
+    }
+
+    /// Sets where [`display`](Self::display) and [`Display`](fmt::Display) get the
+    /// terminal size from.
+    ///
+    /// Defaults to [`Detect`]: the `COLUMNS` and `LINES` environment variables, then the
+    /// terminal itself. When the provider can't tell the size, `display` prints the text
+    /// without a box; give [`Detect`] a fallback width, or set a fixed [`Size`], to draw
+    /// boxes anyway, e.g. into CI logs.
+    ///
+    /// # Arguments
+    ///
+    /// * `provider` - Any [`TerminalSize`]: [`Detect`], [`Tty`](crate::term::Tty), a
+    ///   [`Size`], or a closure
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use boxy_cli::prelude::*;
+    /// use boxy_cli::term::{Detect, Size};
+    ///
+    /// let mut b = Boxy::new(BoxType::Single, "#00ffff");
+    /// b.add_text_sgmt("Tests passed", "#ffffff", BoxAlign::Center);
+    ///
+    /// // boxes at 100 columns in CI, where there is no terminal
+    /// b.set_size_provider(Detect::new().fallback(100));
+    /// b.display();
+    ///
+    /// // always 40 columns
+    /// b.set_size_provider(Size { cols: 40, rows: 24 });
+    /// assert_eq!(format!("{:#}", b).lines().collect::<Vec<_>>(), b.render_plain(40));
+    /// ```
+    pub fn set_size_provider(&mut self, provider: impl TerminalSize + Send + Sync + 'static) {
+        self.size_provider = Some(Provider(Box::new(provider)));
+    }
+
+    /// Sets how the border is colored: a solid color, a gradient, or one color per side.
+    ///
+    /// Defaults to [`BorderPaint::Solid`], which uses the box color. Gradients are computed
+    /// per glyph, so they stretch to whatever width and height the box renders at.
+    ///
+    /// # Arguments
+    ///
+    /// * `paint` - The [`BorderPaint`] to use. Invalid hex colors fall back to white with a stderr warning
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use boxy_cli::prelude::*;
+    ///
+    /// let mut b = Boxy::new(BoxType::Rounded, "#888888");
+    /// // a red status stripe down the left edge
+    /// b.set_border_paint(BorderPaint::Sides {
+    ///     top: None,
+    ///     right: None,
+    ///     bottom: None,
+    ///     left: Some("#ff0000".to_string()),
+    /// });
+    /// b.add_text_sgmt("Build failed", "#ffffff", BoxAlign::Left);
+    /// b.display();
+    /// ```
+    pub fn set_border_paint(&mut self, paint: BorderPaint) {
+        self.paint = Paint::resolve(&paint);
+    }
+
+    /// Sets a separate border style for the dividers between segments and the separators
+    /// between columns.
+    ///
+    /// By default the dividers match the outer frame, so a [`BoxType::Bold`] box also has
+    /// bold dividers. With a divider style set, the frame keeps its own glyphs and the
+    /// junctions where dividers meet it switch to mixed-weight glyphs such as `┠`, `┯` or
+    /// `╟`. Where no mixed glyph exists (heavy with double), the frame's own junction is used.
+    ///
+    /// # Arguments
+    ///
+    /// * `style` - The [`BoxType`] whose lines and junctions are used inside the frame
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use boxy_cli::prelude::*;
+    ///
+    /// let mut b = Boxy::new(BoxType::Bold, "#00ffff");
+    /// b.set_divider_style(BoxType::Single); // heavy frame, thin dividers
+    /// b.add_text_sgmt("Header", "#ffffff", BoxAlign::Center);
+    /// b.add_text_sgmt("Body", "#ffffff", BoxAlign::Left);
+    /// assert!(b.render(20)[2].contains("┠───"));
+    /// ```
+    pub fn set_divider_style(&mut self, style: BoxType) {
+        self.divider_style = Some(style);
+    }
+
+    /// Sets what separates segment `seg_index` from the segment after it.
+    ///
+    /// Every boundary gets a [`Divider::Rule`] unless set otherwise. Use a styled or
+    /// labeled rule to mark a new section, or [`Divider::Spacer`] / [`Divider::None`] to let
+    /// related segments sit together. The divider set on the last segment is unused.
+    ///
+    /// # Arguments
+    ///
+    /// * `seg_index` - Zero-based index of the segment above the boundary
+    /// * `divider` - The [`Divider`] to draw below it
+    ///
+    /// # Panics
+    ///
+    /// Panics if `seg_index` is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use boxy_cli::prelude::*;
+    ///
+    /// let mut b = Boxy::new(BoxType::Single, "#00ffff");
+    /// b.add_text_sgmt("Build", "#ffffff", BoxAlign::Center);
+    /// b.add_text_sgmt("Compiled 12 crates", "#ffffff", BoxAlign::Left);
+    /// b.set_divider(0, Divider::Styled(BoxType::Dashed));
+    /// assert!(b.render(30)[2].contains("┄┄┄"));
+    /// ```
+    pub fn set_divider(&mut self, seg_index: usize, divider: Divider) {
+        assert!(
+            seg_index < self.data.len(),
+            "set_divider: seg_index {} is out of bounds ({} segments exist)",
+            seg_index,
+            self.data.len()
+        );
+        self.seg_dividers[seg_index] = divider;
+    }
+
     /// Sets the border color using a hex color code.
     ///
     /// Can be called at any point before [`display`](Self::display). On an invalid hex
@@ -613,6 +930,7 @@ impl Boxy {
     /// - `seg_index` is out of bounds
     /// - The segment at `seg_index` is a `Single` text segment, not columnar
     /// - The length of `ratios` does not match the column count of the segment
+    /// - The ratios add up to 0
     ///
     /// # Examples
     ///
@@ -644,9 +962,50 @@ impl Boxy {
             self.seg_cols_count[seg_index],
             ratios.len()
         );
+        assert!(
+            ratios.iter().sum::<usize>() > 0,
+            "set_segment_ratios: the column ratios of segment {} add up to 0",
+            seg_index
+        );
         self.seg_cols_ratio[seg_index] = ratios;
     }
 
+    /// Removes every segment, keeping the box's style: border, colors, padding and width.
+    ///
+    /// Useful to refill a box with fresh content, e.g. between the frames of a
+    /// [`LiveBox`](crate::live::LiveBox).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use boxy_cli::prelude::*;
+    ///
+    /// let mut b = Boxy::new(BoxType::Single, "#00ffff");
+    /// b.add_text_sgmt("3 of 10 done", "#ffffff", BoxAlign::Left);
+    ///
+    /// b.clear_segments();
+    /// b.add_text_sgmt("4 of 10 done", "#ffffff", BoxAlign::Left);
+    /// assert!(b.render_plain(30)[1].contains("4 of 10"));
+    /// ```
+    pub fn clear_segments(&mut self) {
+        self.data.clear();
+        self.colors.clear();
+        self.seg_align.clear();
+        self.sect_count = 0;
+        self.seg_cols_count.clear();
+        self.seg_cols_ratio.clear();
+        self.seg_dividers.clear();
+    }
+
+    // The segments, for the checks of the `try_` methods
+    pub(crate) fn seg_data(&self) -> &[SegType] {
+        &self.data
+    }
+
+    pub(crate) fn int_padding(&self) -> BoxPad {
+        self.int_padding
+    }
+
     #[doc(hidden)]
     #[cfg(test)]
     pub(crate) fn sect_count(&self) -> usize {
@@ -665,6 +1024,86 @@ impl Boxy {
         &self.seg_cols_count
     }
 
+    // Each segment with its colors and alignment, for the Markdown export
+    #[cfg(feature = "markdown")]
+    pub(crate) fn segments(&self) -> impl Iterator<Item = (&SegType, &SegColor, &BoxAlign)> {
+        self.data
+            .iter()
+            .zip(&self.colors)
+            .zip(&self.seg_align)
+            .map(|((seg, colors), align)| (seg, colors, align))
+    }
+
+    #[cfg(feature = "markdown")]
+    pub(crate) fn dividers(&self) -> &[Divider] {
+        &self.seg_dividers
+    }
+
+    /// Captures the box as a serializable [`BoxySpec`].
+    ///
+    /// The spec holds everything but the terminal: rebuild the box anywhere with
+    /// [`Boxy::from_spec`]. Colors come out as lowercase `#rrggbb` strings.
+    ///
+    /// Available with the `serde` cargo feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use boxy_cli::prelude::*;
+    ///
+    /// let mut b = Boxy::new(BoxType::Double, "#00FFFF");
+    /// b.add_text_sgmt("Hello", "#ffffff", BoxAlign::Center);
+    ///
+    /// let spec = b.to_spec();
+    /// assert_eq!(spec.color, "#00ffff");
+    /// assert_eq!(Boxy::from_spec(&spec).unwrap().render(40), b.render(40));
+    /// ```
+    #[cfg(feature = "serde")]
+    pub fn to_spec(&self) -> BoxySpec {
+        use crate::paint::hex;
+        let segments = (0..self.sect_count)
+            .map(|i| {
+                let colors = match &self.colors[i] {
+                    SegColor::Single(colors) => {
+                        SegmentColors::Single(colors.iter().map(|c| hex(*c)).collect())
+                    }
+                    SegColor::Columnar(cols) => SegmentColors::Columnar(
+                        cols.iter()
+                            .map(|col| col.iter().map(|c| hex(*c)).collect())
+                            .collect(),
+                    ),
+                };
+                let ratios = &self.seg_cols_ratio[i];
+                SegmentSpec {
+                    content: self.data[i].clone(),
+                    colors: Some(colors),
+                    align: self.seg_align[i],
+                    // only columnar segments with uneven columns need their ratios
+                    ratios: (matches!(self.data[i], SegType::Columnar(_))
+                        && ratios.iter().any(|r| *r != ratios[0]))
+                    .then(|| ratios.clone()),
+                    divider: self.seg_dividers[i].clone(),
+                }
+            })
+            .collect();
+        BoxySpec {
+            box_type: self.type_enum.clone(),
+            color: hex(self.box_col),
+            border_paint: self.paint.to_border_paint(),
+            shadow: self.shadow.map(ShadowPaint::to_shadow),
+            divider_style: self.divider_style.clone(),
+            align: self.align,
+            internal_padding: self.int_padding,
+            external_padding: self.ext_padding,
+            width: (self.fixed_width != 0).then_some(self.fixed_width),
+            height: (self.fixed_height != 0).then_some(self.fixed_height),
+            ascii_fallback: self.ascii_fallback,
+            color_mode: self.color_mode,
+            non_tty_mode: self.non_tty_mode,
+            segments,
+        }
+    }
+
     /// Renders and displays the text box in the terminal.
     ///
     /// Automatically sizes the box to the current terminal width unless a fixed width
@@ -672,10 +1111,17 @@ impl Boxy {
     /// and configuration are set — subsequent calls re-render with the current terminal
     /// size, so the box will adapt if the terminal was resized between calls.
     ///
+    /// The width comes from the size provider (see
+    /// [`set_size_provider`](Self::set_size_provider)): by default `COLUMNS`, then the
+    /// terminal. When it can't be told, e.g. when output is piped, only the text is printed.
+    ///
     /// Output uses ANSI true-color escape codes. Terminals without true-color support
     /// will fall back gracefully to the nearest available color via the `colored` crate.
     /// On terminals with `NO_COLOR` set or where color is disabled, plain text is emitted.
     ///
+    /// Write errors (e.g. a closed pipe) are ignored. Use [`write_to`](Self::write_to)
+    /// to handle them, or to print somewhere other than stdout.
+    ///
     /// # Examples
     ///
     /// ```
@@ -685,38 +1131,189 @@ impl Boxy {
     /// my_box.add_text_sgmt("Hello, World!", "#ffffff", BoxAlign::Center);
     /// my_box.display();
     /// ```
-    pub fn display(&mut self) {
-        let term_size = match termsize::get() {
-            Some(s) => s.cols as usize,
-            None => {
-                // no tty, so just dunp raw text, no need to pollute stream with pipes and dividers
-                for seg in &self.data {
-                    match seg {
-                        SegType::Single(lines) => {
-                            println!("{}", lines.join("\n"));
-                        }
-                        SegType::Columnar(cols) => {
-                            println!(
-                                "{}",
-                                cols.iter()
-                                    .map(|col| col.join("\n"))
-                                    .collect::<Vec<_>>()
-                                    .join("\n")
-                            );
+    pub fn display(&self) {
+        use std::io::Write;
+        let stdout = io::stdout();
+        let mut handle = io::BufWriter::new(stdout.lock());
+        let _ = match self.term_size() {
+            Some(s) => self.write_to(&mut handle, s.cols),
+            None => self.write_non_tty(&mut handle, self.non_tty_mode.resolve()),
+        };
+        let _ = handle.flush();
+    }
+
+    // Writes the box as `mode` asks for when the terminal size is unknown
+    pub(crate) fn write_non_tty<W: io::Write + ?Sized>(
+        &self,
+        out: &mut W,
+        mode: NonTtyMode,
+    ) -> io::Result<()> {
+        let color_mode = match (mode, self.color_mode) {
+            (NonTtyMode::Plain, _) => ColorMode::Never,
+            // `colored` would leave the colors out, as stdout isn't a terminal
+            (NonTtyMode::Full, ColorMode::Auto) if std::env::var_os("NO_COLOR").is_none() => {
+                ColorMode::Always
+            }
+            (NonTtyMode::Full, color_mode) => color_mode,
+            (NonTtyMode::Structured, _) => return self.write_structured(out),
+            // no tty, so just dump raw text, no need to pollute stream with pipes and dividers
+            _ => return self.write_raw(out),
+        };
+        self.for_each_line(self.frame(FALLBACK_WIDTH), color_mode, |line| {
+            writeln!(out, "{}", line)
+        })
+    }
+
+    // The terminal size from the provider, or as `Detect` finds it
+    pub(crate) fn term_size(&self) -> Option<Size> {
+        match &self.size_provider {
+            Some(Provider(provider)) => provider.size(),
+            None => Detect::new().size(),
+        }
+    }
+
+    // Writes the segments' text without any box around it
+    fn write_raw<W: io::Write + ?Sized>(&self, out: &mut W) -> io::Result<()> {
+        for seg in &self.data {
+            match seg {
+                SegType::Single(lines) => {
+                    writeln!(out, "{}", lines.join("\n"))?;
+                }
+                SegType::Columnar(cols) => {
+                    writeln!(
+                        out,
+                        "{}",
+                        cols.iter()
+                            .map(|col| col.join("\n"))
+                            .collect::<Vec<_>>()
+                            .join("\n")
+                    )?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    // Writes the segments' text line by line, columnar rows as `key: value`, with a blank
+    // line between segments
+    fn write_structured<W: io::Write + ?Sized>(&self, out: &mut W) -> io::Result<()> {
+        for (i, seg) in self.data.iter().enumerate() {
+            if i > 0 {
+                writeln!(out)?;
+            }
+            match seg {
+                SegType::Single(lines) => {
+                    for line in lines {
+                        writeln!(out, "{}", line.trim())?;
+                    }
+                }
+                SegType::Columnar(cols) => {
+                    let rows = cols.iter().map(Vec::len).max().unwrap_or(0);
+                    for row in 0..rows {
+                        let cell = |col: &Vec<String>| {
+                            col.get(row).map_or("", |cell| cell.trim()).to_string()
+                        };
+                        let key = cols.first().map(cell).unwrap_or_default();
+                        let values: Vec<String> = cols[1..]
+                            .iter()
+                            .map(cell)
+                            .filter(|value| !value.is_empty())
+                            .collect();
+                        match (key.is_empty(), values.is_empty()) {
+                            (_, true) => writeln!(out, "{}", key)?,
+                            (true, false) => writeln!(out, "{}", values.join(", "))?,
+                            (false, false) => writeln!(out, "{}: {}", key, values.join(", "))?,
                         }
                     }
                 }
-                return;
             }
-        };
+        }
+        Ok(())
+    }
 
-        use std::io::{self, Write};
-        let lines = self.render(term_size);
-        let stdout = io::stdout();
-        let mut handle = io::BufWriter::new(stdout.lock());
-        for line in lines {
-            writeln!(handle, "{}", line).unwrap();
+    /// Renders the text box into any [`io::Write`]: stderr, a file, a socket, a `Vec<u8>`...
+    ///
+    /// The box is laid out in full first, since border paints such as gradients depend on
+    /// its final size. Lines are then painted and written one at a time, each followed by
+    /// `\n`, without collecting the painted strings into a `Vec<String>`. The first write
+    /// error stops rendering and is returned. The writer is not flushed.
+    ///
+    /// # Arguments
+    ///
+    /// * `out` - The writer to render into
+    /// * `term_width` - The total column width to render into, as for [`render`](Self::render)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use boxy_cli::prelude::*;
+    ///
+    /// let mut b = Boxy::new(BoxType::Single, "#ff5555");
+    /// b.add_text_sgmt("Build failed", "#ffffff", BoxAlign::Center);
+    ///
+    /// // to stderr
+    /// b.write_to(&mut std::io::stderr(), 40)?;
+    ///
+    /// // into memory
+    /// let mut buf = Vec::new();
+    /// b.write_to(&mut buf, 40)?;
+    /// assert_eq!(String::from_utf8(buf).unwrap().lines().count(), 3);
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    pub fn write_to<W: io::Write + ?Sized>(
+        &self,
+        out: &mut W,
+        term_width: usize,
+    ) -> io::Result<()> {
+        self.for_each_line(self.frame(term_width), self.color_mode, |line| {
+            writeln!(out, "{}", line)
+        })
+    }
+
+    /// Renders the text box into any [`fmt::Write`], such as a `String` or a
+    /// [`fmt::Formatter`].
+    ///
+    /// Like [`write_to`](Self::write_to), the box is laid out in full, then lines are
+    /// painted and written one at a time, each followed by `\n`, and the first error is
+    /// returned.
+    ///
+    /// # Arguments
+    ///
+    /// * `out` - The writer to render into
+    /// * `term_width` - The total column width to render into, as for [`render`](Self::render)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use boxy_cli::prelude::*;
+    ///
+    /// let mut b = Boxy::new(BoxType::Rounded, "#00ffff");
+    /// b.add_text_sgmt("Hello!", "#ffffff", BoxAlign::Center);
+    ///
+    /// let mut report = String::from("Status:\n");
+    /// b.fmt_into(&mut report, 30)?;
+    /// assert!(report.ends_with("╯\n"));
+    /// # Ok::<(), std::fmt::Error>(())
+    /// ```
+    pub fn fmt_into<W: fmt::Write + ?Sized>(&self, out: &mut W, term_width: usize) -> fmt::Result {
+        self.for_each_line(self.frame(term_width), self.color_mode, |line| {
+            writeln!(out, "{}", line)
+        })
+    }
+
+    // Lays the box out, then paints the lines one by one, handing each to `f`; stops at the
+    // first error. The layout is built up front: painting needs the box's final size
+    fn for_each_line<E>(
+        &self,
+        frame: Frame,
+        mode: ColorMode,
+        mut f: impl FnMut(&str) -> Result<(), E>,
+    ) -> Result<(), E> {
+        let layout = self.layout_in(frame);
+        for (y, line) in layout.lines.iter().enumerate() {
+            f(&line.paint(&self.paint, self.box_col, mode, y, layout.size))?;
         }
+        Ok(())
     }
 
     /// Renders the text box into a `Vec<String>` without printing to stdout.
@@ -751,25 +1348,157 @@ impl Boxy {
     ///     println!("{}", line);
     /// }
     /// ```
-    pub fn render(&mut self, term_width: usize) -> Vec<String> {
-        let mut output_buffer: Vec<String> = Vec::new();
+    pub fn render(&self, term_width: usize) -> Vec<String> {
+        self.render_in(term_width, self.color_mode)
+    }
+
+    /// Renders the text box like [`render`](Self::render), but never with color escapes.
+    ///
+    /// Borders, layout, padding and text are the same as in colored output; only the
+    /// escape codes are left out. The result is byte-identical whatever the environment
+    /// variables or TTY state, which makes it suited to golden-file tests, emails and
+    /// copy-paste. For the same reason, [`AsciiFallback::Auto`] keeps the chosen glyphs
+    /// instead of checking the locale; only [`AsciiFallback::Always`] draws ASCII here. To get plain output from [`write_to`](Self::write_to) and
+    /// [`fmt_into`](Self::fmt_into), use [`set_color_mode`](Self::set_color_mode).
+    ///
+    /// # Arguments
+    ///
+    /// * `term_width` - The total column width to render into
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use boxy_cli::prelude::*;
+    ///
+    /// let mut b = Boxy::new(BoxType::Rounded, "#00ffff");
+    /// b.add_text_sgmt("Hi", "#ff0000", BoxAlign::Left);
+    /// b.set_width(8);
+    ///
+    /// assert_eq!(b.render_plain(40), vec!["╭──────╮", "│ Hi   │", "╰──────╯"]);
+    /// ```
+    pub fn render_plain(&self, term_width: usize) -> Vec<String> {
+        let layout = self.layout_in(self.plain_frame(term_width));
+        self.paint_layout(layout, ColorMode::Never)
+    }
+
+    fn render_in(&self, term_width: usize, mode: ColorMode) -> Vec<String> {
+        self.paint_layout(self.layout(term_width), mode)
+    }
+
+    fn paint_layout(&self, layout: Layout, mode: ColorMode) -> Vec<String> {
+        layout
+            .lines
+            .iter()
+            .enumerate()
+            .map(|(y, line)| line.paint(&self.paint, self.box_col, mode, y, layout.size))
+            .collect()
+    }
+
+    // The box as runs of one color each, line by line, for the export formats
+    #[cfg(any(feature = "html", feature = "svg"))]
+    pub(crate) fn runs(&self, term_width: usize) -> Vec<Vec<Run>> {
+        let layout = self.layout(term_width);
+        layout
+            .lines
+            .iter()
+            .enumerate()
+            .map(|(y, line)| line.runs(&self.paint, self.box_col, y, layout.size))
+            .collect()
+    }
 
+    // Width available between the left and right borders
+    pub(crate) fn disp_width(&self, term_width: usize) -> usize {
         // Fix width to accommodate for box characters
-        let disp_width = if self.fixed_width != 0 {
+        if self.fixed_width != 0 {
             self.fixed_width.saturating_sub(2)
         } else {
+            // leave room for the shadow, too
             term_width
                 .saturating_sub(self.ext_padding.lr())
                 .saturating_sub(2)
+                .saturating_sub(self.shadow.map_or(0, |s| s.offset.0))
                 .max(1)
-        };
+        }
+    }
+
+    // Lays the box out line by line; colors are applied afterwards, once every border
+    // glyph's position in the box is known
+    pub(crate) fn layout(&self, term_width: usize) -> Layout {
+        self.layout_in(self.frame(term_width))
+    }
+
+    fn layout_in(&self, frame: Frame) -> Layout {
+        let (mut output_buffer, above) = self.draw_open(&frame);
+        output_buffer.push(self.bottom_border(&frame, &above));
+
+        let size = (frame.disp_width + 2, output_buffer.len());
+        if let Some(shadow) = self.shadow {
+            cast_shadow(&mut output_buffer, shadow, frame.margin, size);
+        }
+        Layout {
+            lines: output_buffer,
+            size,
+        }
+    }
+
+    // Columns from the left edge of the terminal to the right of the box and its shadow
+    pub(crate) fn extent(&self, term_width: usize) -> usize {
+        let frame = self.frame(term_width);
+        frame.margin + frame.disp_width + 2 + self.shadow.map_or(0, |s| s.offset.0)
+    }
+
+    // Where the box sits at this terminal width, and the glyphs it is drawn with
+    pub(crate) fn frame(&self, term_width: usize) -> Frame {
+        self.frame_in(term_width, self.ascii_fallback.applies())
+    }
+
+    // As `frame`, but `Auto` keeps the chosen glyphs, so plain output doesn't depend on the locale
+    fn plain_frame(&self, term_width: usize) -> Frame {
+        self.frame_in(term_width, self.ascii_fallback == AsciiFallback::Always)
+    }
+
+    fn frame_in(&self, term_width: usize, ascii: bool) -> Frame {
+        let disp_width = self.disp_width(term_width);
 
-        // Parse box color only once per display
-        let box_col_truecolor = self.box_col;
         // Resolve template once per display
-        let box_pieces = map_box_type(&self.type_enum);
-        // get alignment-based offset
-        let align_offset = align_offset(&disp_width, &term_width, &self.align, &self.ext_padding);
+        let mut box_pieces = border_set(&self.type_enum, ascii);
+        let divider_pieces = match &self.divider_style {
+            Some(style) => border_set(style, ascii),
+            None => with_fallback(map_divider_type(&self.type_enum), ascii),
+        };
+        if self.divider_style.is_some() {
+            box_pieces = box_pieces.joined_to(&divider_pieces);
+        }
+        // get alignment-based offset, placing the box and its shadow as one
+        let shadow_width = self.shadow.map_or(0, |s| s.offset.0);
+        let align_offset = align_offset(
+            &(disp_width + shadow_width),
+            &term_width,
+            &self.align,
+            &self.ext_padding,
+        );
+        Frame {
+            disp_width,
+            align_offset,
+            margin: self.ext_padding.left + align_offset,
+            box_pieces,
+            divider_pieces,
+            ascii,
+        }
+    }
+
+    // The top border and every segment, without the bottom border; also returns the
+    // column boundaries of the last segment, which the bottom border joins
+    fn draw_open(&self, frame: &Frame) -> (Vec<Line>, Vec<usize>) {
+        let mut output_buffer: Vec<Line> = Vec::new();
+        let Frame {
+            disp_width,
+            align_offset,
+            margin,
+            box_pieces,
+            divider_pieces,
+            ..
+        } = *frame;
 
         // pre-emptively get the dividers map:
         let (col_widths_segwise, col_boundaries_segwise): (Vec<Vec<usize>>, Vec<Vec<usize>>) = (0
@@ -784,132 +1513,128 @@ impl Boxy {
             })
             .unzip();
 
-        // Preparing the top segment
-        let mut top_seg: String = String::with_capacity(disp_width + self.ext_padding.left + 4);
-        match self.data.first() {
-            None | Some(&SegType::Single(_)) => {
-                write!(
-                    top_seg,
-                    "{:>width$}",
-                    box_pieces.top_left,
-                    width = self.ext_padding.left + align_offset
-                )
-                .unwrap();
-                top_seg.push_str(&box_pieces.horizontal.to_string().repeat(disp_width));
-                top_seg.push(box_pieces.top_right);
-            }
-            Some(&SegType::Columnar(_)) => {
-                write!(
-                    top_seg,
-                    "{:>width$}",
-                    box_pieces.top_left,
-                    width = self.ext_padding.left + align_offset
-                )
-                .unwrap();
-                let below = self.col_boundaries(&col_widths_segwise[0]);
-                for i in 0..disp_width {
-                    match below.contains(&i) {
-                        true => {
-                            top_seg.push(box_pieces.upper_t);
-                        }
-                        false => {
-                            top_seg.push(box_pieces.horizontal);
-                        }
-                    };
-                }
-                top_seg.push(box_pieces.top_right);
+        // Preparing the top segment; columns in the first segment join it from below
+        let mut top_seg = Line::new();
+        top_seg.edge(margin, box_pieces.top_left);
+        let below = col_boundaries_segwise.first().map_or(&[][..], |b| &b[..]);
+        for i in 0..disp_width {
+            match below.contains(&i) {
+                true => top_seg.border(box_pieces.upper_t),
+                false => top_seg.border(box_pieces.horizontal),
             }
         }
-        // push top segment onto the buffer
-        output_buffer.push(top_seg.color(box_col_truecolor).to_string());
+        top_seg.border(box_pieces.top_right);
+        output_buffer.push(top_seg);
 
         // Iteratively render all the textbox sections, with appropriate dividers in between
         for i in 0..self.sect_count {
             if i > 0 {
-                output_buffer.push(self.render_h_divider(
-                    &box_col_truecolor,
-                    disp_width,
-                    align_offset,
-                    &box_pieces,
+                output_buffer.extend(self.render_h_divider(
+                    &self.seg_dividers[i - 1],
+                    frame,
                     &col_boundaries_segwise[i - 1],
                     &col_boundaries_segwise[i],
                 ));
             }
             if let SegType::Single(_) = self.data[i] {
-                // need to move to render, instead of print
-                self.render_segment(
-                    i,
-                    disp_width,
-                    align_offset,
-                    &box_pieces,
-                    &box_col_truecolor,
-                    &mut output_buffer,
-                );
+                self.render_segment(i, disp_width, align_offset, &box_pieces, &mut output_buffer);
             } else {
-                // need to move to render, instead of print
                 self.render_cols(
                     i,
                     align_offset,
-                    &box_pieces,
-                    &box_col_truecolor,
+                    (&box_pieces, &divider_pieces),
                     &col_widths_segwise[i],
                     &mut output_buffer,
                 );
             }
         }
-        // Rendering the bottom segment
-        let mut bot_seg: String = String::with_capacity(disp_width + self.ext_padding.left + 4);
-        match self.data.last() {
-            None | Some(&SegType::Single(_)) => {
-                write!(
-                    bot_seg,
-                    "{:>width$}",
-                    box_pieces.bottom_left,
-                    width = self.ext_padding.left + align_offset
-                )
-                .unwrap();
-                bot_seg.push_str(&box_pieces.horizontal.to_string().repeat(disp_width));
-                bot_seg.push(box_pieces.bottom_right);
-            }
-            Some(&SegType::Columnar(_)) => {
-                write!(
-                    bot_seg,
-                    "{:>width$}",
-                    box_pieces.bottom_left,
-                    width = self.ext_padding.left + align_offset
-                )
-                .unwrap();
-                let above = self.col_boundaries(
-                    col_widths_segwise
-                        .last()
-                        .expect("failed to get last element"),
-                );
-                for i in 0..disp_width {
-                    match above.contains(&i) {
-                        true => {
-                            bot_seg.push(box_pieces.lower_t);
-                        }
-                        false => {
-                            bot_seg.push(box_pieces.horizontal);
-                        }
-                    };
-                }
-                bot_seg.push(box_pieces.bottom_right);
+        let above = col_boundaries_segwise.last().cloned().unwrap_or_default();
+        (output_buffer, above)
+    }
+
+    // Rendering the bottom segment; columns in the last segment join it from above
+    fn bottom_border(&self, frame: &Frame, above: &[usize]) -> Line {
+        let box_pieces = frame.box_pieces;
+        let mut bot_seg = Line::new();
+        bot_seg.edge(frame.margin, box_pieces.bottom_left);
+        for i in 0..frame.disp_width {
+            match above.contains(&i) {
+                true => bot_seg.border(box_pieces.lower_t),
+                false => bot_seg.border(box_pieces.bottom_horizontal()),
             }
         }
-        output_buffer.push(bot_seg.color(box_col_truecolor).to_string());
+        bot_seg.border(box_pieces.bottom_right);
+        bot_seg
+    }
 
+    // Streaming, for `BoxWriter`: the top of the box with its own segments, the column
+    // boundaries the next rule joins, and the divider after the last segment, if any
+    pub(crate) fn stream_open(&self, frame: &Frame) -> (Vec<Line>, Vec<usize>, Option<Divider>) {
+        let (lines, above) = self.draw_open(frame);
+        (lines, above, self.seg_dividers.last().cloned())
+    }
+
+    // One streamed line of text, wrapped and bordered
+    pub(crate) fn stream_text(
+        &self,
+        frame: &Frame,
+        text: &str,
+        color: Color,
+        align: &BoxAlign,
+    ) -> Vec<Line> {
+        let processed_data = text.trim().to_owned() + " ";
+        let liner = text_wrap_vec_fast(&processed_data, frame.disp_width, &self.int_padding);
+        let ext_offset = BoxPad {
+            left: frame.margin,
+            ..self.ext_padding
+        };
+        let mut output_buffer = Vec::new();
+        iter_line_rndr(
+            &liner,
+            &frame.box_pieces,
+            &color,
+            &frame.disp_width,
+            (&ext_offset, &self.int_padding),
+            align,
+            &mut output_buffer,
+        );
         output_buffer
     }
 
+    // A divider between streamed segments, which start out as plain text
+    pub(crate) fn stream_divider(
+        &self,
+        frame: &Frame,
+        divider: &Divider,
+        above: &[usize],
+    ) -> Option<Line> {
+        self.render_h_divider(divider, frame, above, &[])
+    }
+
+    pub(crate) fn stream_close(&self, frame: &Frame, above: &[usize]) -> Line {
+        self.bottom_border(frame, above)
+    }
+
+    // Paints a streamed line. The height of a box still being written isn't known, so
+    // `last` marks the bottom border, and gradients along the height run across instead.
+    pub(crate) fn stream_paint(&self, line: &Line, y: usize, last: bool, frame: &Frame) -> String {
+        let height = if last { y + 1 } else { y + 2 };
+        line.paint(
+            &self.paint.streamed(),
+            self.box_col,
+            self.color_mode,
+            y,
+            (frame.disp_width + 2, height),
+        )
+    }
+
     fn render_segment(
-        &mut self,
+        &self,
         seg_index: usize,
         disp_width: usize,
         align_offset: usize,
-        box_pieces: &BoxTemplates,
-        box_col_truecolor: &Color,
-        output_buffer: &mut Vec<String>,
+        box_pieces: &BorderSet,
+        output_buffer: &mut Vec<Line>,
     ) {
         let lines = match &self.data[seg_index] {
             SegType::Single(lines) => lines,
@@ -924,8 +1649,6 @@ impl Boxy {
             down: self.ext_padding.down,
         };
 
-        let vertical: ColoredString = box_pieces.vertical.to_string().color(*box_col_truecolor);
-
         for i in 0..lines.len() {
             // obtaining text colour truevalues
             let text_col_truecolor = match &self.colors[seg_index] {
@@ -938,13 +1661,12 @@ impl Boxy {
             let liner: Vec<String> =
                 text_wrap_vec_fast(&processed_data, disp_width, &self.int_padding);
 
-            // Actually printing shiet
             // Iterative printing. Migrated from recursive to prevent stack overflows with larger text bodies and reduce complexity,
             // also to improve code efficiency
             iter_line_rndr(
                 &liner,
                 box_pieces,
-                (box_col_truecolor, &text_col_truecolor),
+                &text_col_truecolor,
                 &disp_width,
                 (&ext_offset, &self.int_padding),
                 &self.seg_align[seg_index],
@@ -953,58 +1675,97 @@ impl Boxy {
 
             // printing an empty line between consecutive non-terminal text line
             if i < lines.len() - 1 {
-                output_buffer.push(format!(
-                    "{1:>width$}{}{1}",
-                    " ".repeat(disp_width),
-                    vertical,
-                    width = self.ext_padding.left + align_offset
-                ));
+                let mut blank = Line::new();
+                blank.edge(ext_offset.left, box_pieces.vertical);
+                blank.space(disp_width);
+                blank.border(box_pieces.right_vertical());
+                output_buffer.push(blank);
             }
         }
     }
 
     fn render_h_divider(
         &self,
-        box_col_truecolor: &Color,
-        disp_width: usize,
-        align_offset: usize,
-        box_pieces: &BoxTemplates,
+        divider: &Divider,
+        frame: &Frame,
         above_boundaries: &[usize],
         below_boundaries: &[usize],
-    ) -> String {
-        let mut div: String = String::with_capacity(disp_width + self.ext_padding.left + 4);
-
-        write!(
-            div,
-            "{:>width$}",
-            box_pieces.left_t.to_string(),
-            width = self.ext_padding.left + align_offset
-        )
-        .unwrap();
-        for i in 0..disp_width {
-            let ch = match (above_boundaries.contains(&i), below_boundaries.contains(&i)) {
-                (true, true) => box_pieces.cross,
-                (false, true) => box_pieces.upper_t,
-                (true, false) => box_pieces.lower_t,
-                (false, false) => box_pieces.horizontal,
-            };
-            div.push(ch);
+    ) -> Option<Line> {
+        let (mut box_pieces, mut inner) = (frame.box_pieces, frame.divider_pieces);
+        let (disp_width, margin) = (frame.disp_width, frame.margin);
+        let mut div = Line::new();
+
+        match divider {
+            Divider::None => return None,
+            Divider::Spacer => {
+                // keep column separators that continue through the gap
+                div.edge(margin, box_pieces.vertical);
+                for i in 0..disp_width {
+                    match above_boundaries.contains(&i) && below_boundaries.contains(&i) {
+                        true => div.border(inner.vertical),
+                        false => div.space(1),
+                    }
+                }
+                div.border(box_pieces.right_vertical());
+                return Some(div);
+            }
+            Divider::Styled(style) => {
+                inner = border_set(style, frame.ascii);
+                box_pieces = box_pieces.joined_to(&inner);
+            }
+            Divider::Rule | Divider::Labeled(_) => {}
+        }
+
+        div.edge(margin, box_pieces.left_t);
+        let rule: Vec<char> = (0..disp_width)
+            .map(
+                |i| match (above_boundaries.contains(&i), below_boundaries.contains(&i)) {
+                    (true, true) => inner.cross,
+                    (false, true) => inner.upper_t,
+                    (true, false) => inner.lower_t,
+                    (false, false) => inner.horizontal,
+                },
+            )
+            .collect();
+        // a label and its spaces sit on plain rule glyphs, at least two from either end
+        // and clear of every junction
+        let clear: Vec<bool> = (0..disp_width)
+            .map(|i| {
+                i >= 2
+                    && i + 2 < disp_width
+                    && !above_boundaries.contains(&i)
+                    && !below_boundaries.contains(&i)
+            })
+            .collect();
+        let (label, start) = match divider {
+            Divider::Labeled(label) => label_slot(label.trim(), &clear),
+            _ => (String::new(), 0),
+        };
+        let label_width = UnicodeWidthStr::width(label.as_str());
+        if label_width > 0 {
+            div.borders(rule[..start].iter().copied());
+            div.space(1);
+            div.text(&label, self.box_col);
+            div.space(1);
+            div.borders(rule[start + label_width + 2..].iter().copied());
+        } else {
+            div.borders(rule);
         }
         // push right segment
-        div.push(box_pieces.right_t);
+        div.border(box_pieces.right_t);
 
-        div.color(*box_col_truecolor).to_string()
+        Some(div)
     }
 
     fn render_cols(
         &self,
         seg_index: usize,
         align_offset: usize,
-        box_pieces: &BoxTemplates,
-        box_col_truecolor: &Color,
+        pieces: (&BorderSet, &BorderSet),
         col_seg_widths: &[usize],
-        output_buffer: &mut Vec<String>,
+        output_buffer: &mut Vec<Line>,
     ) {
+        let (box_pieces, inner) = pieces;
         let col_count = self.seg_cols_count[seg_index];
 
         let mut columnar_data: Vec<Vec<(String, Color)>> = Vec::new();
@@ -1035,35 +1796,25 @@ impl Boxy {
             columnar_data.push(col_wrapped);
         }
 
-        let vertical = box_pieces.vertical.to_string().color(*box_col_truecolor);
-
         for curr_line in 0..col_height_max {
-            let mut currline = String::new();
-            write!(
-                currline,
-                "{:>width$}",
-                vertical,
-                width = self.ext_padding.left + align_offset
-            )
-            .unwrap();
+            let mut currline = Line::new();
+            currline.edge(self.ext_padding.left + align_offset, box_pieces.vertical);
             for (i, col) in columnar_data.iter().enumerate() {
                 if i > 0 {
-                    write!(currline, "{}", vertical).unwrap();
+                    currline.border(inner.vertical);
                 }
                 let width = col_seg_widths[i].saturating_sub(1);
                 match col.get(curr_line) {
                     Some((content, color)) => {
                         let col_width = UnicodeWidthStr::width(content.as_str());
-                        let fill = width.saturating_sub(col_width);
-                        write!(currline, " {}", content.color(*color)).unwrap();
-                        write!(currline, "{:<fill$}", "", fill = fill).unwrap();
-                    }
-                    None => {
-                        write!(currline, " {:<width$}", "", width = width).unwrap();
+                        currline.space(1);
+                        currline.text(content, *color);
+                        currline.space(width.saturating_sub(col_width));
                     }
+                    None => currline.space(1 + width),
                 }
             }
-            write!(currline, "{}", vertical).unwrap();
+            currline.border(box_pieces.right_vertical());
             output_buffer.push(currline);
         }
     }
@@ -1104,6 +1855,11 @@ impl Boxy {
     }
 }
 
+// Columns `text_wrap_vec_fast` wraps text at, in `disp_width` with `int_padding`
+pub(crate) fn text_room(disp_width: usize, int_padding: &BoxPad) -> usize {
+    disp_width.saturating_sub(int_padding.lr() + 2)
+}
+
 // Faster non-allocating whitespace scanning text wrapper
 // Returns wrapped text, line by line in a vec
 #[doc(hidden)]
@@ -1112,7 +1868,7 @@ pub(crate) fn text_wrap_vec_fast(
     disp_width: usize,
     int_padding: &BoxPad,
 ) -> Vec<String> {
-    let max_cols = disp_width.saturating_sub(int_padding.lr() + 2);
+    let max_cols = text_room(disp_width, int_padding);
     if max_cols == 0 {
         return Vec::new();
     }
@@ -1143,6 +1899,11 @@ pub(crate) fn text_wrap_vec_fast(
             current_cols += w;
             end += g.len();
         }
+        // a grapheme wider than a whole line never fits; leave it out instead of looping
+        if end == start {
+            start += data[start..].graphemes(true).next().map_or(1, str::len);
+            continue;
+        }
 
         // If we didn't reach the end of the string, word-break at the last space.
         // If no space was found, hard-break at the column limit.
@@ -1162,34 +1923,86 @@ pub(crate) fn text_wrap_vec_fast(
     liner
 }
 
+// Cuts text down to at most `width` display columns, on grapheme boundaries
+// Places a divider label on the `clear` columns of a rule: centred when that spot is clear,
+// otherwise centred in the widest clear run and truncated to fit it. Returns the label as
+// drawn (empty when no run has room) and the column of the space before it.
+fn label_slot(label: &str, clear: &[bool]) -> (String, usize) {
+    let mut runs: Vec<(usize, usize)> = Vec::new();
+    for (i, &free) in clear.iter().enumerate() {
+        match runs.last_mut() {
+            Some((start, len)) if free && *start + *len == i => *len += 1,
+            _ if free => runs.push((i, 1)),
+            _ => {}
+        }
+    }
+
+    let width = UnicodeWidthStr::width(label);
+    let centred = (clear.len().saturating_sub(width + 2)) / 2;
+    if width > 0
+        && runs
+            .iter()
+            .any(|&(start, len)| start <= centred && centred + width + 2 <= start + len)
+    {
+        return (label.to_string(), centred);
+    }
+    let Some(&(start, len)) = runs.iter().rev().max_by_key(|&&(_, len)| len) else {
+        return (String::new(), 0);
+    };
+    let label = truncate_to_width(label, len.saturating_sub(2));
+    match UnicodeWidthStr::width(label.as_str()) {
+        0 => (String::new(), 0),
+        width => (label, start + (len - width - 2) / 2),
+    }
+}
+
+fn truncate_to_width(text: &str, width: usize) -> String {
+    let mut out = String::new();
+    let mut used = 0;
+    for grapheme in text.graphemes(true) {
+        let w = UnicodeWidthStr::width(grapheme);
+        if used + w > width {
+            break;
+        }
+        used += w;
+        out.push_str(grapheme);
+    }
+    out
+}
+
+// Spaces between a left or right aligned line and the padding on its other side, so the
+// line spans `disp_width`
+fn fill_width(text: &str, disp_width: usize, int_padding: &BoxPad) -> usize {
+    disp_width
+        .saturating_sub(int_padding.left.max(1))
+        .saturating_sub(int_padding.right.max(1))
+        .saturating_sub(UnicodeWidthStr::width(text))
+}
+
 #[doc(hidden)]
 fn iter_line_rndr(
     liner: &[String],
-    box_pieces: &BoxTemplates,
-    context_colors: (&Color, &Color),
+    box_pieces: &BorderSet,
+    text_col: &Color,
     disp_width: &usize,
     padding: (&BoxPad, &BoxPad),
     align: &BoxAlign,
-    output_buffer: &mut Vec<String>,
+    output_buffer: &mut Vec<Line>,
 ) {
-    let (box_col, text_col): (&Color, &Color) = context_colors;
     let (ext_padding, int_padding) = padding;
-    let printable_area = disp_width - int_padding.lr(); // IDK why this works, but it does
-    let vertical = box_pieces.vertical.to_string().color(*box_col);
+    let printable_area = disp_width.saturating_sub(int_padding.lr()); // IDK why this works, but it does
+    // padding runs are at least one column wide, so text never touches the border
     match align {
         BoxAlign::Left => {
             for i in liner.iter() {
-                let col_width = UnicodeWidthStr::width(i.as_str());
-                let fill = printable_area
-                    .saturating_sub(col_width)
-                    .saturating_sub(2 * ((int_padding.right == 0) as usize)); // subbing 2 for dynamic sizing w/o internal padding  -> bars on each end
-                let mut currline = String::new();
-                write!(currline, "{:>width$}", vertical, width = ext_padding.left).unwrap();
-                write!(currline, "{:<pad$}", " ", pad = int_padding.left).unwrap();
-                write!(currline, "{}", i.color(*text_col)).unwrap();
-                write!(currline, "{:<fill$}", " ", fill = fill).unwrap();
-                write!(currline, "{:<pad$}", " ", pad = int_padding.right).unwrap();
-                write!(currline, "{}", vertical).unwrap();
+                let fill = fill_width(i, *disp_width, int_padding);
+                let mut currline = Line::new();
+                currline.edge(ext_padding.left, box_pieces.vertical);
+                currline.space(int_padding.left.max(1));
+                currline.text(i, *text_col);
+                currline.space(fill);
+                currline.space(int_padding.right.max(1));
+                currline.border(box_pieces.right_vertical());
                 output_buffer.push(currline);
             }
         }
@@ -1198,40 +2011,25 @@ fn iter_line_rndr(
                 let text = i.trim_end();
                 // display_width not .len(): "日".len()==3 but it only takes 2 columns
                 let remaining = printable_area.saturating_sub(UnicodeWidthStr::width(text));
-                let mut currline = String::new();
-                write!(currline, "{:>width$}", vertical, width = ext_padding.left).unwrap();
-                write!(
-                    currline,
-                    "{:<pad$}",
-                    " ",
-                    pad = int_padding.left + remaining / 2
-                )
-                .unwrap();
-                write!(currline, "{}", text.color(*text_col)).unwrap();
-                write!(
-                    currline,
-                    "{:<pad$}",
-                    " ",
-                    pad = int_padding.right + remaining - remaining / 2
-                )
-                .unwrap();
-                write!(currline, "{}", vertical).unwrap();
+                let mut currline = Line::new();
+                currline.edge(ext_padding.left, box_pieces.vertical);
+                currline.space((int_padding.left + remaining / 2).max(1));
+                currline.text(text, *text_col);
+                currline.space((int_padding.right + remaining - remaining / 2).max(1));
+                currline.border(box_pieces.right_vertical());
                 output_buffer.push(currline);
             }
         }
         BoxAlign::Right => {
             for i in liner.iter() {
-                let col_width = UnicodeWidthStr::width(i.as_str());
-                let fill = printable_area
-                    .saturating_sub(col_width)
-                    .saturating_sub(2 * ((int_padding.right == 0) as usize)); // subbing 2 for dynamic sizing w/o internal padding  -> bars on each end
-                let mut currline = String::new();
-                write!(currline, "{:>width$}", vertical, width = ext_padding.left).unwrap();
-                write!(currline, "{:<pad$}", " ", pad = int_padding.left).unwrap();
-                write!(currline, "{:<fill$}", " ", fill = fill).unwrap();
-                write!(currline, "{}", i.color(*text_col)).unwrap();
-                write!(currline, "{:<pad$}", " ", pad = int_padding.right).unwrap();
-                write!(currline, "{}", vertical).unwrap();
+                let fill = fill_width(i, *disp_width, int_padding);
+                let mut currline = Line::new();
+                currline.edge(ext_padding.left, box_pieces.vertical);
+                currline.space(int_padding.left.max(1));
+                currline.space(fill);
+                currline.text(i, *text_col);
+                currline.space(int_padding.right.max(1));
+                currline.border(box_pieces.right_vertical());
                 output_buffer.push(currline);
             }
         }
@@ -1240,9 +2038,10 @@ fn iter_line_rndr(
 
 // returns the box template for the given enum
 #[doc(hidden)]
-fn map_box_type(boxtype: &BoxType) -> BoxTemplates {
+pub(crate) fn map_box_type(boxtype: &BoxType) -> BorderSet {
     match boxtype {
         BoxType::Classic => CLASSIC_TEMPLATE,
+        BoxType::Ascii => ASCII_TEMPLATE,
         BoxType::Single => SINGLE_TEMPLATE,
         BoxType::DoubleHorizontal => DOUB_H_TEMPLATE,
         BoxType::DoubleVertical => DOUB_V_TEMPLATE,
@@ -1250,7 +2049,36 @@ fn map_box_type(boxtype: &BoxType) -> BoxTemplates {
         BoxType::Bold => BOLD_TEMPLATE,
         BoxType::Rounded => ROUNDED_TEMPLATE,
         BoxType::BoldCorners => BOLD_CORNERS_TEMPLATE,
+        BoxType::Dashed => DASHED_TEMPLATE,
+        BoxType::DashedBold => DASHED_BOLD_TEMPLATE,
+        BoxType::Dotted => DOTTED_TEMPLATE,
+        BoxType::DottedBold => DOTTED_BOLD_TEMPLATE,
+        BoxType::Block => BLOCK_TEMPLATE,
+        BoxType::DoubleOuter => DOUBLE_OUTER_TEMPLATE,
         BoxType::Empty => EMPTY_TEMPLATE,
+        BoxType::Custom(set) => set.sanitized(),
+    }
+}
+
+// The border glyphs for a style, swapped for ASCII when `ascii` is set
+fn border_set(box_type: &BoxType, ascii: bool) -> BorderSet {
+    with_fallback(map_box_type(box_type), ascii)
+}
+
+fn with_fallback(set: BorderSet, ascii: bool) -> BorderSet {
+    match ascii {
+        true => set.to_ascii(),
+        false => set,
+    }
+}
+
+// The glyphs for dividers and column separators inside a frame of this type, when no
+// divider style is set. They match the frame except for `DoubleOuter`, which draws them
+// with single lines
+fn map_divider_type(boxtype: &BoxType) -> BorderSet {
+    match boxtype {
+        BoxType::DoubleOuter => DOUBLE_OUTER_INNER_TEMPLATE,
+        _ => map_box_type(boxtype),
     }
 }
 
@@ -1261,10 +2089,33 @@ fn align_offset(
     align: &BoxAlign,
     padding: &BoxPad,
 ) -> usize {
+    // saturating: a box wider than the terminal, or asymmetric padding, pins it left
     match *align {
         BoxAlign::Left => 0,
-        BoxAlign::Center => (term_size - disp_width) / 2 - padding.left,
-        BoxAlign::Right => term_size - (disp_width + 2 * padding.right + padding.left),
+        BoxAlign::Center => {
+            (term_size.saturating_sub(*disp_width) / 2).saturating_sub(padding.left)
+        }
+        BoxAlign::Right => term_size.saturating_sub(disp_width + 2 * padding.right + padding.left),
+    }
+}
+
+// Adds the shadow: `offset.0` cells after each line from `offset.1` down, then `offset.1`
+// rows under the box, shifted right by `offset.0`
+fn cast_shadow(
+    lines: &mut Vec<Line>,
+    shadow: ShadowPaint,
+    margin: usize,
+    (width, height): (usize, usize),
+) {
+    let (dx, dy) = shadow.offset;
+    for line in lines.iter_mut().skip(dy) {
+        line.shadow(dx, shadow);
+    }
+    for _ in 0..dy.min(height) {
+        let mut line = Line::new();
+        line.space(margin.saturating_sub(1) + dx);
+        line.shadow(width, shadow);
+        lines.push(line);
     }
 }
 
@@ -1293,30 +2144,62 @@ pub fn resolve_pad(dat: String) -> BoxPad {
 #[doc(hidden)]
 #[allow(dead_code)]
 pub fn resolve_align(dat: String) -> BoxAlign {
-    match &*dat {
-        "center" => BoxAlign::Center,
-        "right" => BoxAlign::Right,
-        "left" => BoxAlign::Left,
-        _ => BoxAlign::Left,
-    }
+    BoxAlign::from_name(&dat).unwrap_or(BoxAlign::Left)
 }
 // Macro type-resolution function
 #[doc(hidden)]
 #[allow(dead_code)]
-pub fn resolve_type(dat: String) -> BoxType {
-    match &*dat {
-        "classic" | "c" => BoxType::Classic,
-        "single" | "s" => BoxType::Single,
-        "double_horizontal" | "dh" => BoxType::DoubleHorizontal,
-        "double_vertical" | "dv" => BoxType::DoubleVertical,
-        "double" | "d" => BoxType::Double,
-        "bold" | "b" => BoxType::Bold,
-        "rounded" | "r" => BoxType::Rounded,
-        "bold_corners" | "bc" => BoxType::BoldCorners,
-        "empty" | "e" => BoxType::Empty,
-        _ => BoxType::Single,
+pub fn resolve_type(dat: impl MacroBoxType) -> BoxType {
+    dat.into_box_type()
+}
+
+// What `boxy!(type: ...)` accepts: a `BoxType`, passed through as is, or a style name
+#[doc(hidden)]
+pub trait MacroBoxType {
+    fn into_box_type(self) -> BoxType;
+}
+
+impl MacroBoxType for BoxType {
+    fn into_box_type(self) -> BoxType {
+        self
+    }
+}
+
+impl MacroBoxType for &BoxType {
+    fn into_box_type(self) -> BoxType {
+        self.clone()
+    }
+}
+
+impl MacroBoxType for &str {
+    fn into_box_type(self) -> BoxType {
+        type_from_name(self)
+    }
+}
+
+impl MacroBoxType for String {
+    fn into_box_type(self) -> BoxType {
+        type_from_name(&self)
+    }
+}
+
+// A style name or its shorthand; unknown names fall back to `Single`
+fn type_from_name(dat: &str) -> BoxType {
+    match dat {
+        "c" => BoxType::Classic,
+        "a" => BoxType::Ascii,
+        "s" => BoxType::Single,
+        "dh" => BoxType::DoubleHorizontal,
+        "dv" => BoxType::DoubleVertical,
+        "d" => BoxType::Double,
+        "b" => BoxType::Bold,
+        "r" => BoxType::Rounded,
+        "bc" => BoxType::BoldCorners,
+        "e" => BoxType::Empty,
+        name => BoxType::from_name(name).unwrap_or(BoxType::Single),
     }
 }
+
 // Macro type-resolution function
 #[doc(hidden)]
 #[allow(dead_code)]
@@ -1354,6 +2237,9 @@ pub struct BoxyBuilder {
     type_enum: BoxType,
     data: Vec<SegType>,
     box_col: Color,
+    // kept as given, so `try_build` can check their colors
+    paint: BorderPaint,
+    shadow: Option<Shadow>,
     colors: Vec<SegColor>,
     int_padding: BoxPad,
     ext_padding: BoxPad,
@@ -1362,8 +2248,16 @@ pub struct BoxyBuilder {
     fixed_width: usize,
     fixed_height: usize,
     seg_cols_ratio: Vec<Vec<usize>>,
+    seg_dividers: Vec<Divider>,
     terminal_width_offset: i32,
     seg_col_count: Vec<usize>,
+    ascii_fallback: AsciiFallback,
+    divider_style: Option<BoxType>,
+    color_mode: ColorMode,
+    non_tty_mode: NonTtyMode,
+    size_provider: Option<Provider>,
+    // what went wrong so far, reported by `build` or `try_build`
+    errors: Vec<BoxyError>,
 }
 
 impl Default for BoxyBuilder {
@@ -1407,6 +2301,8 @@ impl BoxyBuilder {
             type_enum: BoxType::Single,
             data: Vec::new(),
             box_col: Color::White,
+            paint: BorderPaint::Solid,
+            shadow: None,
             colors: Vec::new(),
             int_padding: BoxPad::new(),
             ext_padding: BoxPad::new(),
@@ -1415,8 +2311,15 @@ impl BoxyBuilder {
             fixed_width: 0,
             fixed_height: 0,
             seg_cols_ratio: Vec::new(),
+            seg_dividers: Vec::new(),
             terminal_width_offset: -20,
             seg_col_count: Vec::new(),
+            ascii_fallback: AsciiFallback::Never,
+            divider_style: None,
+            color_mode: ColorMode::Auto,
+            non_tty_mode: NonTtyMode::Auto,
+            size_provider: None,
+            errors: Vec::new(),
         }
     }
 
@@ -1485,7 +2388,7 @@ impl BoxyBuilder {
     ///
     /// The actual appearance depends on terminal support for colors.
     pub fn color(mut self, box_color: &str) -> Self {
-        self.box_col = SegColor::parse_hexcolor(box_color);
+        self.box_col = self.color_or_white(box_color);
         self
     }
 
@@ -1518,9 +2421,9 @@ impl BoxyBuilder {
     ///     .build();
     /// ```
     pub fn add_segment(mut self, text: &str, color: &str, text_align: BoxAlign) -> Self {
+        let color = self.color_or_white(color);
         self.data.push(SegType::Single(vec![text.to_string()]));
-        self.colors
-            .push(SegColor::Single(vec![SegColor::parse_hexcolor(color)]));
+        self.colors.push(SegColor::Single(vec![color]));
         self.seg_align.push(text_align);
         self.seg_col_count.push(0); // Single segment, no columns
         self.seg_cols_ratio.push(vec![1]); // placeholder, mirrors add_text_sgmt
@@ -1545,7 +2448,8 @@ impl BoxyBuilder {
     ///
     /// # Panics
     ///
-    /// Panics if `column_count` is 0.
+    /// [`build`](Self::build) panics if `column_count` is 0, and
+    /// [`try_build`](Self::try_build) returns [`BoxyError::ZeroColumns`].
     ///
     /// # Examples
     ///
@@ -1559,10 +2463,10 @@ impl BoxyBuilder {
     ///     .build();
     /// ```
     pub fn add_col_segment(mut self, text_align: BoxAlign, column_count: usize) -> Self {
-        assert!(
-            column_count > 0,
-            "add_col_segment: column_count must be at least 1"
-        );
+        if column_count == 0 {
+            self.errors.push(BoxyError::ZeroColumns);
+            return self;
+        }
         self.data
             .push(SegType::Columnar(vec![Vec::new(); column_count]));
         self.colors
@@ -1588,6 +2492,11 @@ impl BoxyBuilder {
     ///
     /// The builder instance for method chaining
     ///
+    /// # Panics
+    ///
+    /// [`build`](Self::build) panics if the last segment is columnar, and
+    /// [`try_build`](Self::try_build) returns [`BoxyError::NotSingle`].
+    ///
     /// # Examples
     ///
     /// ```
@@ -1604,24 +2513,20 @@ impl BoxyBuilder {
     /// ```
     ///
     pub fn add_line(mut self, text: &str, color: &str) -> Self {
-        if let Some(last) = self.data.last_mut() {
-            match last {
-                SegType::Single(lines) => lines.push(text.to_string()),
-                SegType::Columnar(_) => panic!("add_line called on Columnar segment"),
-            }
-            match self
-                .colors
-                .last_mut()
-                .expect("colors out of sync with data")
-            {
-                SegColor::Single(cols) => cols.push(SegColor::parse_hexcolor(color)),
-                SegColor::Columnar(_) => panic!("add_line called on Columnar segment"),
-            }
+        if let Some(SegType::Columnar(_)) = self.data.last() {
+            self.errors.push(BoxyError::NotSingle(self.data.len() - 1));
+            return self;
+        }
+        let color = self.color_or_white(color);
+        if let (Some(SegType::Single(lines)), Some(SegColor::Single(cols))) =
+            (self.data.last_mut(), self.colors.last_mut())
+        {
+            lines.push(text.to_string());
+            cols.push(color);
         } else {
             // no segment yet — create one, mirroring add_segment
             self.data.push(SegType::Single(vec![text.to_string()]));
-            self.colors
-                .push(SegColor::Single(vec![SegColor::parse_hexcolor(color)]));
+            self.colors.push(SegColor::Single(vec![color]));
             self.seg_align.push(BoxAlign::Left);
             self.seg_col_count.push(0);
             self.seg_cols_ratio.push(vec![1]);
@@ -1646,8 +2551,9 @@ impl BoxyBuilder {
     ///
     /// # Panics
     ///
-    /// Panics if no segment exists, if the last segment is not columnar, or if `col_index`
-    /// is out of bounds.
+    /// [`build`](Self::build) panics if no segment exists, if the last segment is not
+    /// columnar, or if `col_index` is out of bounds; [`try_build`](Self::try_build) returns
+    /// the [`BoxyError`] instead.
     ///
     /// # Examples
     ///
@@ -1665,22 +2571,13 @@ impl BoxyBuilder {
     ///     .build();
     /// ```
     pub fn add_col_line(mut self, text: &str, color: &str, col_index: usize) -> Self {
-        let seg_index = self.data.len() - 1;
-        match &mut self.data[seg_index] {
-            SegType::Columnar(cols) => {
-                assert!(
-                    col_index < cols.len(),
-                    "add_col_line: col_index out of bounds"
-                );
-                cols[col_index].push(text.to_string());
+        match last_segment(&self.data) {
+            Ok(seg_index) => self.add_col_line_indx(text, color, seg_index, col_index),
+            Err(e) => {
+                self.errors.push(e);
+                self
             }
-            SegType::Single(_) => panic!("add_col_line called on a Single segment"),
-        }
-        match &mut self.colors[seg_index] {
-            SegColor::Columnar(cols) => cols[col_index].push(SegColor::parse_hexcolor(color)),
-            SegColor::Single(_) => panic!("colors shape mismatch"),
         }
-        self
     }
 
     /// Adds a line of text to a specific column of a specific columnar segment by index.
@@ -1702,8 +2599,9 @@ impl BoxyBuilder {
     ///
     /// # Panics
     ///
-    /// Panics if `seg_index` is out of bounds, if that segment is not columnar, or if
-    /// `col_index` is out of bounds for that segment's column count.
+    /// [`build`](Self::build) panics if `seg_index` is out of bounds, if that segment is not
+    /// columnar, or if `col_index` is out of bounds for that segment's column count;
+    /// [`try_build`](Self::try_build) returns the [`BoxyError`] instead.
     ///
     /// # Examples
     ///
@@ -1724,19 +2622,16 @@ impl BoxyBuilder {
         seg_index: usize,
         col_index: usize,
     ) -> Self {
-        match &mut self.data[seg_index] {
-            SegType::Columnar(cols) => {
-                assert!(
-                    col_index < cols.len(),
-                    "add_col_line_indx: col_index out of bounds"
-                );
-                cols[col_index].push(text.to_string());
-            }
-            SegType::Single(_) => panic!("add_col_line_indx called on a Single segment"),
+        if let Err(e) = check_column(&self.data, seg_index, col_index) {
+            self.errors.push(e);
+            return self;
         }
-        match &mut self.colors[seg_index] {
-            SegColor::Columnar(cols) => cols[col_index].push(SegColor::parse_hexcolor(color)),
-            SegColor::Single(_) => panic!("colors shape mismatch"),
+        let color = self.color_or_white(color);
+        if let (SegType::Columnar(cols), SegColor::Columnar(col_colors)) =
+            (&mut self.data[seg_index], &mut self.colors[seg_index])
+        {
+            cols[col_index].push(text.to_string());
+            col_colors[col_index].push(color);
         }
         self
     }
@@ -1980,10 +2875,11 @@ impl BoxyBuilder {
     ///
     /// The builder instance for method chaining
     ///
-    /// # Panics
+    /// # Errors
     ///
-    /// Panics if `seg_index` refers to a Single text segment rather than a columnar one,
-    /// or if `ratios.len()` does not match that segment's column count.
+    /// [`try_build`](Self::try_build) fails if `seg_index` refers to a Single text segment
+    /// rather than a columnar one, if `ratios.len()` does not match that segment's column
+    /// count, or if the ratios add up to 0.
     ///
     /// # Examples
     ///
@@ -2007,6 +2903,42 @@ impl BoxyBuilder {
         self
     }
 
+    /// Sets what separates segment `seg_index` from the segment after it.
+    ///
+    /// Boundaries default to [`Divider::Rule`]. Can be called before the segment is added;
+    /// see [`Boxy::set_divider`] for the available dividers.
+    ///
+    /// # Arguments
+    ///
+    /// * `seg_index` - Zero-based index of the segment above the boundary
+    /// * `divider` - The [`Divider`] to draw below it
+    ///
+    /// # Returns
+    ///
+    /// The builder instance for method chaining
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use boxy_cli::prelude::*;
+    ///
+    /// Boxy::builder()
+    ///     .add_segment("Deploy", "#ffffff", BoxAlign::Center)
+    ///     .segment_divider(0, Divider::Labeled("Steps".to_string()))
+    ///     .add_segment("1. Build", "#ffffff", BoxAlign::Left)
+    ///     .segment_divider(1, Divider::None)
+    ///     .add_segment("2. Ship", "#ffffff", BoxAlign::Left)
+    ///     .build()
+    ///     .display();
+    /// ```
+    pub fn segment_divider(mut self, seg_index: usize, divider: Divider) -> Self {
+        if seg_index >= self.seg_dividers.len() {
+            self.seg_dividers.resize(seg_index + 1, Divider::Rule);
+        }
+        self.seg_dividers[seg_index] = divider;
+        self
+    }
+
     /// Adjusts the effective terminal width used for dynamic box sizing.
     ///
     /// # Note
@@ -2050,6 +2982,215 @@ impl BoxyBuilder {
         self
     }
 
+    /// Sets when the border is drawn in plain ASCII instead of the chosen glyphs.
+    ///
+    /// Defaults to [`AsciiFallback::Never`]. [`AsciiFallback::Auto`] checks the locale and
+    /// terminal each time the box is rendered.
+    ///
+    /// # Arguments
+    ///
+    /// * `fallback` - The [`AsciiFallback`] policy
+    ///
+    /// # Returns
+    ///
+    /// The builder instance for method chaining
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use boxy_cli::prelude::*;
+    ///
+    /// Boxy::builder()
+    ///     .box_type(BoxType::Double)
+    ///     .ascii_fallback(AsciiFallback::Auto)
+    ///     .add_segment("Readable on any console", "#ffffff", BoxAlign::Center)
+    ///     .build()
+    ///     .display();
+    /// ```
+    pub fn ascii_fallback(mut self, fallback: AsciiFallback) -> Self {
+        self.ascii_fallback = fallback;
+        self
+    }
+
+    /// Casts a drop shadow to the right of and below the box.
+    ///
+    /// See [`Boxy::set_shadow`] for how the shadow affects sizing and alignment.
+    ///
+    /// # Arguments
+    ///
+    /// * `shadow` - The [`Shadow`] style, offset and color. An invalid hex color falls back to white with a stderr warning
+    ///
+    /// # Returns
+    ///
+    /// The builder instance for method chaining
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use boxy_cli::prelude::*;
+    ///
+    /// Boxy::builder()
+    ///     .box_type(BoxType::Double)
+    ///     .align(BoxAlign::Center)
+    ///     .shadow(Shadow::new(ShadowStyle::Dim).color("#222222"))
+    ///     .add_segment("Welcome!", "#ffffff", BoxAlign::Center)
+    ///     .build()
+    ///     .display();
+    /// ```
+    pub fn shadow(mut self, shadow: Shadow) -> Self {
+        self.shadow = Some(shadow);
+        self
+    }
+
+    /// Sets whether output is colored; see [`Boxy::set_color_mode`].
+    ///
+    /// # Returns
+    ///
+    /// The builder instance for method chaining
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use boxy_cli::prelude::*;
+    ///
+    /// let b = Boxy::builder()
+    ///     .color("#ffffff")
+    ///     .color_mode(ColorMode::Always)
+    ///     .add_segment("Hi", "#ffffff", BoxAlign::Left)
+    ///     .build();
+    /// assert!(b.render(20)[0].starts_with("\x1b[38;2;255;255;255m"));
+    /// ```
+    pub fn color_mode(mut self, mode: ColorMode) -> Self {
+        self.color_mode = mode;
+        self
+    }
+
+    /// Sets what the box prints when displayed without a known terminal size.
+    ///
+    /// Defaults to [`NonTtyMode::Auto`], which reads `BOXY_NON_TTY` and prints only the
+    /// text when it is unset. See [`Boxy::set_non_tty_mode`].
+    ///
+    /// # Arguments
+    ///
+    /// * `mode` - The [`NonTtyMode`] to use
+    ///
+    /// # Returns
+    ///
+    /// The builder instance for method chaining
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use boxy_cli::prelude::*;
+    ///
+    /// Boxy::builder()
+    ///     .non_tty_mode(NonTtyMode::Plain) // boxes in CI logs too
+    ///     .add_segment("Deployed", "#00ff00", BoxAlign::Center)
+    ///     .build()
+    ///     .display();
+    /// ```
+    pub fn non_tty_mode(mut self, mode: NonTtyMode) -> Self {
+        self.non_tty_mode = mode;
+        self
+    }
+
+    /// Sets where the box gets the terminal size from when displayed.
+    ///
+    /// Defaults to [`Detect`], which reads `COLUMNS` and `LINES` before asking the
+    /// terminal. See [`Boxy::set_size_provider`].
+    ///
+    /// # Arguments
+    ///
+    /// * `provider` - Any [`TerminalSize`], such as [`Detect`] with a fallback width or a
+    ///   fixed [`Size`]
+    ///
+    /// # Returns
+    ///
+    /// The builder instance for method chaining
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use boxy_cli::prelude::*;
+    /// use boxy_cli::term::Detect;
+    ///
+    /// Boxy::builder()
+    ///     .size_provider(Detect::new().fallback(80))
+    ///     .add_segment("Drawn even when piped", "#ffffff", BoxAlign::Left)
+    ///     .build()
+    ///     .display();
+    /// ```
+    pub fn size_provider(mut self, provider: impl TerminalSize + Send + Sync + 'static) -> Self {
+        self.size_provider = Some(Provider(Box::new(provider)));
+        self
+    }
+
+    /// Sets how the border is colored: a solid color, a gradient, or one color per side.
+    ///
+    /// Defaults to [`BorderPaint::Solid`], which uses the color set with
+    /// [`color`](Self::color). See [`Boxy::set_border_paint`].
+    ///
+    /// # Arguments
+    ///
+    /// * `paint` - The [`BorderPaint`] to use. Invalid hex colors fall back to white with a stderr warning
+    ///
+    /// # Returns
+    ///
+    /// The builder instance for method chaining
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use boxy_cli::prelude::*;
+    ///
+    /// Boxy::builder()
+    ///     .box_type(BoxType::Double)
+    ///     .border_paint(BorderPaint::Gradient {
+    ///         direction: GradientDirection::Perimeter,
+    ///         stops: vec!["#00ffff".to_string(), "#ff00ff".to_string()],
+    ///     })
+    ///     .add_segment("Now with gradients", "#ffffff", BoxAlign::Center)
+    ///     .build()
+    ///     .display();
+    /// ```
+    pub fn border_paint(mut self, paint: BorderPaint) -> Self {
+        self.paint = paint;
+        self
+    }
+
+    /// Sets a separate border style for the dividers between segments and the separators
+    /// between columns.
+    ///
+    /// The outer frame keeps the style set with [`box_type`](Self::box_type), and the
+    /// junctions where the two meet use mixed-weight glyphs (`┠`, `┯`, `╟`, ...). See
+    /// [`Boxy::set_divider_style`].
+    ///
+    /// # Arguments
+    ///
+    /// * `style` - The [`BoxType`] whose lines and junctions are used inside the frame
+    ///
+    /// # Returns
+    ///
+    /// The builder instance for method chaining
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use boxy_cli::prelude::*;
+    ///
+    /// Boxy::builder()
+    ///     .box_type(BoxType::Double)
+    ///     .divider_style(BoxType::Dashed) // double frame, dashed dividers
+    ///     .add_segment("Header", "#ffffff", BoxAlign::Center)
+    ///     .add_segment("Body", "#ffffff", BoxAlign::Left)
+    ///     .build()
+    ///     .display();
+    /// ```
+    pub fn divider_style(mut self, style: BoxType) -> Self {
+        self.divider_style = Some(style);
+        self
+    }
+
     /// Consumes the builder and returns a configured [`Boxy`] instance ready to display.
     /// (use .display() to output the box to stdout)
     ///
@@ -2058,7 +3199,7 @@ impl BoxyBuilder {
     /// ```
     /// use boxy_cli::prelude::*;
     ///
-    /// let mut b = Boxy::builder()
+    /// let b = Boxy::builder()
     ///     .box_type(BoxType::Double)
     ///     .color("#00ffff")
     ///     .add_segment("Hello, boxy-cli!", "#ffffff", BoxAlign::Center)
@@ -2077,12 +3218,131 @@ impl BoxyBuilder {
     ///     .build()
     ///     .display();
     /// ```
-    pub fn build(self) -> Boxy {
+    ///
+    /// # Panics
+    ///
+    /// Panics on anything [`try_build`](Self::try_build) reports, except invalid colors:
+    /// those fall back to white with a warning on stderr. Use `try_build` to get every
+    /// problem as a [`BoxyError`] instead.
+    pub fn build(mut self) -> Boxy {
+        for e in &self.errors {
+            if let BoxyError::InvalidColor(hex) = e {
+                // the warning `parse_hexcolor` prints
+                SegColor::parse_hexcolor(hex);
+            }
+        }
+        self.errors
+            .retain(|e| !matches!(e, BoxyError::InvalidColor(_)));
+        if let Err(e) = self.check(false) {
+            panic!("BoxyBuilder: {}", e);
+        }
+        self.assemble()
+    }
+
+    /// Consumes the builder and returns the configured [`Boxy`], after checking the whole
+    /// spec.
+    ///
+    /// Unlike [`build`](Self::build), this never panics or prints anything.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first problem found, in this order:
+    /// - anything that went wrong while building: a line added to a segment or column that
+    ///   doesn't exist, a columnar segment without columns, or an invalid color
+    /// - a custom border or divider style with a glyph that isn't one column wide
+    /// - an invalid color in the border paint or the shadow
+    /// - a fixed width too narrow for the borders and the internal padding
+    /// - column ratios that don't match their segment's column count, or that add up to 0
+    /// - ratios or a divider set for a segment that was never added
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use boxy_cli::prelude::*;
+    ///
+    /// let result = Boxy::builder()
+    ///     .add_col_segment(BoxAlign::Left, 2)
+    ///     .add_col_line("Name", "#ffffff", 0)
+    ///     .add_col_line("Status", "#ffffff", 1)
+    ///     .segment_ratios(0, vec![1, 2, 1])
+    ///     .try_build();
+    /// assert_eq!(
+    ///     result.unwrap_err(),
+    ///     BoxyError::RatioCount { segment: 0, columns: 2, ratios: 3 }
+    /// );
+    /// ```
+    pub fn try_build(self) -> Result<Boxy, BoxyError> {
+        self.check(true)?;
+        Ok(self.assemble())
+    }
+
+    // The checks behind `try_build`, in its order; `build` leaves out the colors, which
+    // fall back to white
+    fn check(&self, colors: bool) -> Result<(), BoxyError> {
+        if let Some(e) = self.errors.first() {
+            return Err(e.clone());
+        }
+        check_type(&self.type_enum)?;
+        if let Some(style) = &self.divider_style {
+            check_type(style)?;
+        }
+        if colors {
+            check_paint(&self.paint)?;
+            if let Some(shadow) = &self.shadow {
+                check_color(&shadow.color)?;
+            }
+        }
+        check_width(self.fixed_width, &self.int_padding)?;
+        let count = self.data.len();
+        for (seg_index, ratios) in self.seg_cols_ratio.iter().enumerate() {
+            match self.data.get(seg_index) {
+                Some(SegType::Columnar(_)) => check_ratios(&self.data, seg_index, ratios)?,
+                // single segments hold a placeholder ratio
+                Some(SegType::Single(_)) if ratios.as_slice() == [1] => {}
+                Some(SegType::Single(_)) => return Err(BoxyError::NotColumnar(seg_index)),
+                None if ratios.is_empty() => {}
+                None => {
+                    return Err(BoxyError::SegmentIndex {
+                        index: seg_index,
+                        count,
+                    });
+                }
+            }
+        }
+        for divider in &self.seg_dividers {
+            check_divider(divider)?;
+        }
+        if self.seg_dividers.len() > count {
+            return Err(BoxyError::SegmentIndex {
+                index: self.seg_dividers.len() - 1,
+                count,
+            });
+        }
+        Ok(())
+    }
+
+    // The color, or white after noting the error
+    fn color_or_white(&mut self, hex: &str) -> Color {
+        match check_color(hex) {
+            Ok(()) => SegColor::parse_hexcolor(hex),
+            Err(e) => {
+                self.errors.push(e);
+                Color::White
+            }
+        }
+    }
+
+    fn assemble(self) -> Boxy {
+        // one divider per segment, whether set before or after the segment was added
+        let mut seg_dividers = self.seg_dividers;
+        seg_dividers.resize(self.data.len(), Divider::Rule);
         Boxy {
             type_enum: self.type_enum,
             sect_count: self.data.len(),
             data: self.data,
             box_col: self.box_col,
+            paint: Paint::resolve(&self.paint),
+            shadow: self.shadow.as_ref().map(ShadowPaint::resolve),
             colors: self.colors,
             int_padding: self.int_padding,
             ext_padding: self.ext_padding,
@@ -2092,7 +3352,13 @@ impl BoxyBuilder {
             fixed_height: self.fixed_height,
             seg_cols_count: self.seg_col_count,
             seg_cols_ratio: self.seg_cols_ratio,
+            seg_dividers,
             terminal_width_offset: self.terminal_width_offset,
+            ascii_fallback: self.ascii_fallback,
+            divider_style: self.divider_style,
+            color_mode: self.color_mode,
+            non_tty_mode: self.non_tty_mode,
+            size_provider: self.size_provider,
         }
     }
 }
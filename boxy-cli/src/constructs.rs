@@ -4,6 +4,8 @@ use std::fmt::Display;
 
 use colored::Color;
 
+use crate::templates::{BorderSet, BorderSetError};
+
 /// Defines the border style for the text box.
 ///
 /// Each variant represents a different visual style for the box borders.
@@ -17,10 +19,16 @@ use colored::Color;
 /// let mut box2 = Boxy::new(BoxType::Rounded, "#00ffff");
 /// let mut box3 = Boxy::new(BoxType::Bold, "#00ffff");
 /// ```
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum BoxType {
-    /// Simple ASCII-style box using `+` for corners and `-` for borders
+    /// `+` corners and `-` edges, with dashed `┇` verticals. Not pure ASCII — use
+    /// [`Ascii`](BoxType::Ascii) where only 7-bit output is safe
     Classic,
+    /// Strict 7-bit ASCII box using `+` for corners and junctions, `-` and `|` for edges.
+    /// Safe on legacy consoles and in log collectors
+    Ascii,
     /// Default style using single-line Unicode box drawing characters
     #[default]
     Single,
@@ -36,8 +44,26 @@ pub enum BoxType {
     Rounded,
     /// Box with bold corners and normal edges
     BoldCorners,
+    /// Light dashed lines (`┄` and `┆`)
+    Dashed,
+    /// Heavy dashed lines (`┅` and `┇`)
+    DashedBold,
+    /// Light dotted lines (`┈` and `┊`)
+    Dotted,
+    /// Heavy dotted lines (`┉` and `┋`)
+    DottedBold,
+    /// Solid block frame: `█` sides with half blocks (`▀`/`▄`) along the top and bottom
+    Block,
+    /// Double-line frame with single-line dividers and column separators inside
+    DoubleOuter,
     /// Box with no borders (invisible)
     Empty,
+    /// Box drawn with a user-defined [`BorderSet`].
+    ///
+    /// Prefer [`BoxType::custom`], which validates the glyphs up front. Glyphs that aren't
+    /// one column wide are replaced by their [`Single`](BoxType::Single) counterparts at
+    /// render time.
+    Custom(BorderSet),
 }
 
 // Added Display Fucntion to resolve type errors in the macro
@@ -45,6 +71,7 @@ impl Display for BoxType {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let str = match &self {
             BoxType::Classic => "classic".to_string(),
+            BoxType::Ascii => "ascii".to_string(),
             BoxType::Single => "single".to_string(),
             BoxType::DoubleHorizontal => "double_horizontal".to_string(),
             BoxType::DoubleVertical => "double_vertical".to_string(),
@@ -52,12 +79,229 @@ impl Display for BoxType {
             BoxType::Bold => "bold".to_string(),
             BoxType::Rounded => "rounded".to_string(),
             BoxType::BoldCorners => "bold_corners".to_string(),
+            BoxType::Dashed => "dashed".to_string(),
+            BoxType::DashedBold => "dashed_bold".to_string(),
+            BoxType::Dotted => "dotted".to_string(),
+            BoxType::DottedBold => "dotted_bold".to_string(),
+            BoxType::Block => "block".to_string(),
+            BoxType::DoubleOuter => "double_outer".to_string(),
             BoxType::Empty => "empty".to_string(),
+            BoxType::Custom(_) => "custom".to_string(),
         };
         write!(f, "{}", str)
     }
 }
 
+impl BoxType {
+    /// Creates a [`BoxType::Custom`] after checking that every glyph is one column wide.
+    ///
+    /// # Errors
+    ///
+    /// Returns the [`BorderSetError`] from [`BorderSet::validate`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use boxy_cli::prelude::*;
+    ///
+    /// let set = BorderSet::from_glyphs("┌┐└┘│─├┤┬┴┼").unwrap();
+    /// let mut b = Boxy::new(BoxType::custom(set).unwrap(), "#00ffff");
+    /// b.add_text_sgmt("Custom borders", "#ffffff", BoxAlign::Center);
+    /// ```
+    pub fn custom(set: BorderSet) -> Result<Self, BorderSetError> {
+        set.validate()?;
+        Ok(BoxType::Custom(set))
+    }
+
+    /// The names of the built-in styles, as [`from_name`](Self::from_name) accepts them.
+    pub const NAMES: [&'static str; 16] = [
+        "classic",
+        "ascii",
+        "single",
+        "double_horizontal",
+        "double_vertical",
+        "double",
+        "bold",
+        "rounded",
+        "bold_corners",
+        "dashed",
+        "dashed_bold",
+        "dotted",
+        "dotted_bold",
+        "block",
+        "double_outer",
+        "empty",
+    ];
+
+    /// Looks up a built-in style by name: the strict inverse of the `Display` output, for
+    /// config files and command-line parsing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use boxy_cli::prelude::*;
+    ///
+    /// assert_eq!(BoxType::from_name("double_outer"), Some(BoxType::DoubleOuter));
+    /// assert_eq!(BoxType::from_name("custom"), None);
+    /// ```
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "classic" => Some(BoxType::Classic),
+            "ascii" => Some(BoxType::Ascii),
+            "single" => Some(BoxType::Single),
+            "double_horizontal" => Some(BoxType::DoubleHorizontal),
+            "double_vertical" => Some(BoxType::DoubleVertical),
+            "double" => Some(BoxType::Double),
+            "bold" => Some(BoxType::Bold),
+            "rounded" => Some(BoxType::Rounded),
+            "bold_corners" => Some(BoxType::BoldCorners),
+            "dashed" => Some(BoxType::Dashed),
+            "dashed_bold" => Some(BoxType::DashedBold),
+            "dotted" => Some(BoxType::Dotted),
+            "dotted_bold" => Some(BoxType::DottedBold),
+            "block" => Some(BoxType::Block),
+            "double_outer" => Some(BoxType::DoubleOuter),
+            "empty" => Some(BoxType::Empty),
+            _ => None,
+        }
+    }
+}
+
+/// What separates one segment from the next.
+///
+/// Set per boundary with [`Boxy::set_divider`](crate::boxer::Boxy::set_divider) or
+/// [`BoxyBuilder::segment_divider`](crate::boxer::BoxyBuilder::segment_divider). Boundaries
+/// that aren't set get a [`Rule`](Divider::Rule).
+///
+/// # Examples
+///
+/// ```
+/// use boxy_cli::prelude::*;
+///
+/// let mut b = Boxy::new(BoxType::Single, "#00ffff");
+/// b.add_text_sgmt("Summary", "#ffffff", BoxAlign::Left);
+/// b.add_text_sgmt("Line one", "#ffffff", BoxAlign::Left);
+/// b.add_text_sgmt("Line two", "#ffffff", BoxAlign::Left);
+/// b.set_divider(0, Divider::Labeled("Details".to_string())); // ├── Details ──┤
+/// b.set_divider(1, Divider::None); // keep the two lines together
+/// b.display();
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum Divider {
+    /// A full rule in the box's divider style
+    #[default]
+    Rule,
+    /// A full rule drawn in another style, e.g. [`BoxType::Dashed`] or [`BoxType::Double`]
+    Styled(BoxType),
+    /// A rule with the text centered in it, truncated if it doesn't fit
+    Labeled(String),
+    /// A blank line with no rule
+    Spacer,
+    /// No separation: the next segment starts on the following line
+    None,
+}
+
+/// Controls when a box swaps its border glyphs for plain ASCII.
+///
+/// Box-drawing characters turn into mojibake on serial consoles, under `LANG=C`, and in
+/// some log collectors. With a fallback active, every [`BoxType`] is drawn with its closest
+/// ASCII equivalent: corners, junctions and the cross become `+`, vertical edges `|`, and
+/// horizontal edges `-` (or `=` for double and heavy lines).
+///
+/// # Examples
+///
+/// ```
+/// use boxy_cli::prelude::*;
+///
+/// let mut b = Boxy::new(BoxType::Double, "#00ffff");
+/// b.set_ascii_fallback(AsciiFallback::Auto); // ASCII only if the terminal lacks UTF-8
+/// b.add_text_sgmt("Works over serial too", "#ffffff", BoxAlign::Center);
+/// b.display();
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum AsciiFallback {
+    /// Always draw the chosen border glyphs
+    #[default]
+    Never,
+    /// Draw ASCII borders when [`supports_utf8`](crate::term::supports_utf8) returns `false`.
+    /// [`Boxy::render_plain`](crate::boxer::Boxy::render_plain) treats it as `Never`
+    Auto,
+    /// Always draw ASCII borders
+    Always,
+}
+
+impl AsciiFallback {
+    // Whether borders should be drawn in ASCII right now
+    pub(crate) fn applies(&self) -> bool {
+        match self {
+            AsciiFallback::Never => false,
+            AsciiFallback::Auto => !crate::term::supports_utf8(),
+            AsciiFallback::Always => true,
+        }
+    }
+}
+
+/// Controls what [`Boxy::display`](crate::boxer::Boxy::display) prints when it can't
+/// tell the terminal size, e.g. when output is piped or goes to a CI log.
+///
+/// Boxes are drawn at 80 columns in the [`Plain`](Self::Plain) and [`Full`](Self::Full)
+/// modes; for another width, give the box a size provider with a fallback instead (see
+/// [`Boxy::set_size_provider`](crate::boxer::Boxy::set_size_provider)).
+///
+/// # Examples
+///
+/// ```
+/// use boxy_cli::prelude::*;
+///
+/// let mut b = Boxy::new(BoxType::Single, "#00ffff");
+/// b.set_non_tty_mode(NonTtyMode::Plain); // real boxes in CI logs, without escapes
+/// b.add_text_sgmt("Tests passed", "#ffffff", BoxAlign::Center);
+/// b.display();
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum NonTtyMode {
+    /// Chosen by the `BOXY_NON_TTY` environment variable (`raw`, `plain`, `full` or
+    /// `structured`), and [`Raw`](Self::Raw) when it is unset or unknown
+    #[default]
+    Auto,
+    /// Only the text, one line after another, without borders or dividers
+    Raw,
+    /// The box without color escapes
+    Plain,
+    /// The box with its colors, as on a terminal. [`ColorMode::Auto`](crate::paint::ColorMode::Auto)
+    /// counts as [`Always`](crate::paint::ColorMode::Always) here, unless `NO_COLOR` is set.
+    Full,
+    /// The text with its structure kept in plain lines: columnar segments as
+    /// `key: value` lines, with the first column as keys and the others as values, and a
+    /// blank line between segments
+    Structured,
+}
+
+impl NonTtyMode {
+    // The mode to use right now, with `Auto` looked up in the environment
+    pub(crate) fn resolve(&self) -> NonTtyMode {
+        match self {
+            NonTtyMode::Auto => NonTtyMode::from_env(std::env::var("BOXY_NON_TTY").ok().as_deref()),
+            mode => *mode,
+        }
+    }
+
+    pub(crate) fn from_env(value: Option<&str>) -> NonTtyMode {
+        match value.map(|v| v.trim().to_ascii_lowercase()).as_deref() {
+            Some("plain") => NonTtyMode::Plain,
+            Some("full") => NonTtyMode::Full,
+            Some("structured") => NonTtyMode::Structured,
+            _ => NonTtyMode::Raw,
+        }
+    }
+}
+
 /// Specifies the alignment of text within the text box or the box itself within the terminal.
 ///
 /// This enum is used in two contexts:
@@ -80,7 +324,9 @@ impl Display for BoxType {
 /// // Add a right-aligned text segment
 /// my_box.add_text_sgmt("Right aligned text", "#ffffff", BoxAlign::Right);
 /// ```
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum BoxAlign {
     /// Align the box to the left in the terminal, or align text to the left within a segment
     Left,
@@ -105,6 +351,18 @@ impl Display for BoxAlign {
     }
 }
 
+impl BoxAlign {
+    // Strict inverse of the Display impl, for config and CLI parsing
+    pub(crate) fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "left" => Some(BoxAlign::Left),
+            "center" => Some(BoxAlign::Center),
+            "right" => Some(BoxAlign::Right),
+            _ => None,
+        }
+    }
+}
+
 /// Represents padding values for the text box in all four directions.
 ///
 /// `BoxPad` is used to specify padding between:
@@ -125,7 +383,9 @@ impl Display for BoxAlign {
 /// // Create horizontal/vertical padding
 /// let h_v_padding = BoxPad::vh(1, 3); // 1 vertical, 3 horizontal
 /// ```
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(deny_unknown_fields, default))]
 pub struct BoxPad {
     /// Padding at the top
     pub top: usize,
@@ -267,7 +527,9 @@ impl BoxPad {
 }
 
 #[allow(dead_code)]
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 /// Represents the data layout of a single segment in a [`Boxy`](crate::boxer::Boxy) box.
 ///
 /// Each segment is either a [`Single`](SegType::Single) (plain text, one line per entry)
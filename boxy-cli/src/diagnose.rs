@@ -0,0 +1,228 @@
+//! Layout diagnostics: what a box loses at a given width.
+//!
+//! Rendering never fails: text that doesn't fit is broken mid-word or left out, and a box
+//! too wide for the terminal is drawn anyway. [`Boxy::diagnose`] reports each of these as a
+//! [`LayoutWarning`], so tests can assert that a box still fits at the narrowest terminal
+//! it has to support.
+
+use std::fmt;
+
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+use crate::boxer::{Boxy, DEFAULT_PAD, text_room};
+use crate::constructs::SegType;
+
+/// A problem with how a box lays out at one terminal width, found by
+/// [`Boxy::diagnose`].
+///
+/// Segments, columns and lines are counted from 0, as everywhere else.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LayoutWarning {
+    /// The box, with its external padding and shadow, is wider than the terminal, e.g.
+    /// because of a fixed width. The terminal wraps every line.
+    WiderThanTerminal {
+        /// The columns the box takes up
+        width: usize,
+        /// The terminal width it was laid out for
+        term_width: usize,
+    },
+    /// The internal padding leaves no room for the text of single text segments.
+    PaddingTooLarge {
+        /// The left and right internal padding together
+        padding: usize,
+        /// The width of the box, borders included
+        width: usize,
+    },
+    /// Text, or some of it, doesn't fit on any line and is left out of the box.
+    DroppedContent {
+        /// The segment
+        segment: usize,
+        /// The column, in a columnar segment
+        column: Option<usize>,
+        /// The line within the segment or column
+        line: usize,
+    },
+    /// A column narrower than the longest word in it, which gets broken mid-word.
+    NarrowColumn {
+        /// The segment
+        segment: usize,
+        /// The column
+        column: usize,
+        /// The columns of text that fit on one line
+        width: usize,
+        /// The width of the longest word
+        longest_word: usize,
+    },
+}
+
+impl fmt::Display for LayoutWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LayoutWarning::WiderThanTerminal { width, term_width } => write!(
+                f,
+                "the box takes up {} columns, but the terminal is {} wide",
+                width, term_width
+            ),
+            LayoutWarning::PaddingTooLarge { padding, width } => write!(
+                f,
+                "{} columns of internal padding leave no room for text in a box {} wide",
+                padding, width
+            ),
+            LayoutWarning::DroppedContent {
+                segment,
+                column: None,
+                line,
+            } => write!(
+                f,
+                "text on line {} of segment {} doesn't fit and is left out",
+                line, segment
+            ),
+            LayoutWarning::DroppedContent {
+                segment,
+                column: Some(column),
+                line,
+            } => write!(
+                f,
+                "text on line {} of column {} in segment {} doesn't fit and is left out",
+                line, column, segment
+            ),
+            LayoutWarning::NarrowColumn {
+                segment,
+                column,
+                width,
+                longest_word,
+            } => write!(
+                f,
+                "column {} of segment {} fits {} columns of text, but its longest word is {} wide",
+                column, segment, width, longest_word
+            ),
+        }
+    }
+}
+
+impl Boxy {
+    /// Lays the box out as [`render`](Self::render) would, and reports what doesn't fit.
+    ///
+    /// An empty list means every word is shown whole and the box fits in `term_width`.
+    ///
+    /// # Arguments
+    ///
+    /// * `term_width` - The total column width to lay the box out in
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use boxy_cli::prelude::*;
+    ///
+    /// let mut b = Boxy::new(BoxType::Single, "#00ffff");
+    /// b.add_col_text_sgmt(BoxAlign::Left, 2);
+    /// b.add_col_text_line("id", "#ffffff", &0);
+    /// b.add_col_text_line("internationalization", "#ffffff", &1);
+    ///
+    /// assert!(b.diagnose(80).is_empty());
+    /// assert_eq!(
+    ///     b.diagnose(30),
+    ///     vec![LayoutWarning::NarrowColumn { segment: 0, column: 1, width: 10, longest_word: 20 }]
+    /// );
+    /// ```
+    pub fn diagnose(&self, term_width: usize) -> Vec<LayoutWarning> {
+        let mut warnings = Vec::new();
+        let width = self.extent(term_width);
+        if width > term_width {
+            warnings.push(LayoutWarning::WiderThanTerminal { width, term_width });
+        }
+
+        let disp_width = self.disp_width(term_width);
+        let int_padding = self.int_padding();
+        let room = text_room(disp_width, &int_padding);
+        let data = self.seg_data();
+        if room == 0
+            && int_padding.lr() > 0
+            && data.iter().any(|seg| matches!(seg, SegType::Single(_)))
+        {
+            warnings.push(LayoutWarning::PaddingTooLarge {
+                padding: int_padding.lr(),
+                width: disp_width + 2,
+            });
+        }
+
+        for (segment, seg) in data.iter().enumerate() {
+            match seg {
+                SegType::Single(lines) => {
+                    for (line, text) in lines.iter().enumerate() {
+                        if drops(text, room) {
+                            warnings.push(LayoutWarning::DroppedContent {
+                                segment,
+                                column: None,
+                                line,
+                            });
+                        }
+                    }
+                }
+                SegType::Columnar(cols) => {
+                    let widths = self.col_widths(&segment, &disp_width);
+                    for (column, lines) in cols.iter().enumerate() {
+                        let room = text_room(widths[column], &DEFAULT_PAD);
+                        for (line, text) in lines.iter().enumerate() {
+                            if drops(text, room) {
+                                warnings.push(LayoutWarning::DroppedContent {
+                                    segment,
+                                    column: Some(column),
+                                    line,
+                                });
+                            }
+                        }
+                        // with no room at all, the text was reported as left out
+                        let longest_word = lines
+                            .iter()
+                            .flat_map(|text| text.split_whitespace())
+                            .map(UnicodeWidthStr::width)
+                            .max()
+                            .unwrap_or(0);
+                        if room > 0 && longest_word > room {
+                            warnings.push(LayoutWarning::NarrowColumn {
+                                segment,
+                                column,
+                                width: room,
+                                longest_word,
+                            });
+                        }
+                    }
+                }
+            }
+        }
+        warnings
+    }
+
+    /// Renders the box like [`render`](Self::render), together with what
+    /// [`diagnose`](Self::diagnose) reports at the same width.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use boxy_cli::prelude::*;
+    ///
+    /// let mut b = Boxy::new(BoxType::Single, "#00ffff");
+    /// b.add_text_sgmt("Deployed", "#ffffff", BoxAlign::Center);
+    /// b.set_width(60);
+    ///
+    /// let (lines, warnings) = b.render_with_report(40);
+    /// assert_eq!(lines.len(), 3);
+    /// assert_eq!(
+    ///     warnings,
+    ///     vec![LayoutWarning::WiderThanTerminal { width: 60, term_width: 40 }]
+    /// );
+    /// ```
+    pub fn render_with_report(&self, term_width: usize) -> (Vec<String>, Vec<LayoutWarning>) {
+        (self.render(term_width), self.diagnose(term_width))
+    }
+}
+
+// Whether wrapping `text` at `room` columns leaves any of it out: everything, when there
+// is no room, or the graphemes wider than a whole line
+fn drops(text: &str, room: usize) -> bool {
+    text.graphemes(true)
+        .filter(|g| !g.trim().is_empty())
+        .any(|g| UnicodeWidthStr::width(g) > room)
+}
@@ -0,0 +1,482 @@
+//! The error type of the fallible `try_` methods.
+//!
+//! Most [`Boxy`] and [`BoxyBuilder`](crate::boxer::BoxyBuilder) methods panic on an index
+//! that doesn't exist, and replace an invalid color with white after a warning on stderr.
+//! Their `try_` counterparts check the same things first and return a [`BoxyError`]
+//! instead, leaving the box untouched, and never print anything.
+
+use std::fmt;
+
+use crate::boxer::Boxy;
+use crate::constructs::{BoxAlign, BoxPad, BoxType, Divider, SegType};
+use crate::paint::{BorderPaint, Shadow};
+use crate::templates::BorderSetError;
+
+/// The error returned by the `try_` methods of [`Boxy`] and by
+/// [`BoxyBuilder::try_build`](crate::boxer::BoxyBuilder::try_build).
+///
+/// # Examples
+///
+/// ```
+/// use boxy_cli::prelude::*;
+///
+/// let mut b = Boxy::new(BoxType::Single, "#00ffff");
+/// b.try_add_col_text_sgmt(BoxAlign::Left, 2)?;
+/// assert_eq!(
+///     b.try_add_col_text_line("three", "#ffffff", 2),
+///     Err(BoxyError::ColumnIndex { segment: 0, index: 2, count: 2 })
+/// );
+/// assert_eq!(
+///     b.try_set_color("teal"),
+///     Err(BoxyError::InvalidColor("teal".to_string()))
+/// );
+/// # Ok::<(), BoxyError>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum BoxyError {
+    /// A segment index past the last segment; `count` segments exist.
+    SegmentIndex {
+        /// The index that was given
+        index: usize,
+        /// The number of segments
+        count: usize,
+    },
+    /// A line was added to the last segment before there was any segment.
+    NoSegments,
+    /// The segment is a single text segment where a columnar one is needed.
+    NotColumnar(usize),
+    /// The segment is a columnar segment where a single text segment is needed.
+    NotSingle(usize),
+    /// A column index past the last column of a columnar segment.
+    ColumnIndex {
+        /// The segment
+        segment: usize,
+        /// The index that was given
+        index: usize,
+        /// The number of columns in the segment
+        count: usize,
+    },
+    /// A columnar segment was asked for without any columns.
+    ZeroColumns,
+    /// The number of column ratios doesn't match the number of columns.
+    RatioCount {
+        /// The segment
+        segment: usize,
+        /// The number of columns in the segment
+        columns: usize,
+        /// The number of ratios that were given
+        ratios: usize,
+    },
+    /// The column ratios of a segment add up to 0, leaving no width to share out.
+    ZeroRatios(usize),
+    /// A color that isn't a hex color code such as `#00ffff`.
+    InvalidColor(String),
+    /// A fixed width too narrow for the borders, the internal padding and one column of
+    /// text.
+    Width {
+        /// The width that was given
+        width: usize,
+        /// The narrowest width that fits
+        min: usize,
+    },
+    /// A custom border style with a glyph that isn't one column wide.
+    Border(BorderSetError),
+}
+
+impl fmt::Display for BoxyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BoxyError::SegmentIndex { index, count } => write!(
+                f,
+                "seg_index {} is out of bounds ({} segments exist)",
+                index, count
+            ),
+            BoxyError::NoSegments => f.write_str("no segment has been added yet"),
+            BoxyError::NotColumnar(segment) => {
+                write!(f, "segment {} is not a columnar segment", segment)
+            }
+            BoxyError::NotSingle(segment) => {
+                write!(f, "segment {} is a columnar segment", segment)
+            }
+            BoxyError::ColumnIndex {
+                segment,
+                index,
+                count,
+            } => write!(
+                f,
+                "col_index {} is out of bounds (segment {} has {} columns)",
+                index, segment, count
+            ),
+            BoxyError::ZeroColumns => f.write_str("column_count must be at least 1"),
+            BoxyError::RatioCount {
+                segment,
+                columns,
+                ratios,
+            } => write!(
+                f,
+                "segment {} has {} columns, but {} ratios were given",
+                segment, columns, ratios
+            ),
+            BoxyError::ZeroRatios(segment) => {
+                write!(f, "the column ratios of segment {} add up to 0", segment)
+            }
+            BoxyError::InvalidColor(color) => write!(f, "invalid hex color {:?}", color),
+            BoxyError::Width { width, min } => write!(
+                f,
+                "width {} is too narrow, the box needs at least {} columns",
+                width, min
+            ),
+            BoxyError::Border(e) => write!(f, "invalid border style: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for BoxyError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            BoxyError::Border(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<BorderSetError> for BoxyError {
+    fn from(e: BorderSetError) -> Self {
+        BoxyError::Border(e)
+    }
+}
+
+impl Boxy {
+    /// Creates a new `Boxy` like [`new`](Self::new), but returns an error instead of
+    /// falling back to white.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BoxyError::InvalidColor`] if `box_color` isn't a hex color code, and
+    /// [`BoxyError::Border`] if a [`BoxType::Custom`] has a glyph that isn't one column wide.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use boxy_cli::prelude::*;
+    ///
+    /// assert!(Boxy::try_new(BoxType::Rounded, "#00ffff").is_ok());
+    /// assert!(Boxy::try_new(BoxType::Rounded, "cyan").is_err());
+    /// ```
+    pub fn try_new(box_type: BoxType, box_color: &str) -> Result<Self, BoxyError> {
+        check_type(&box_type)?;
+        check_color(box_color)?;
+        Ok(Boxy::new(box_type, box_color))
+    }
+
+    /// Adds a plain-text segment like [`add_text_sgmt`](Self::add_text_sgmt).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BoxyError::InvalidColor`] if `color` isn't a hex color code.
+    pub fn try_add_text_sgmt(
+        &mut self,
+        data_string: &str,
+        color: &str,
+        text_align: BoxAlign,
+    ) -> Result<(), BoxyError> {
+        check_color(color)?;
+        self.add_text_sgmt(data_string, color, text_align);
+        Ok(())
+    }
+
+    /// Adds a columnar segment like [`add_col_text_sgmt`](Self::add_col_text_sgmt).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BoxyError::ZeroColumns`] if `column_count` is 0.
+    pub fn try_add_col_text_sgmt(
+        &mut self,
+        text_align: BoxAlign,
+        column_count: usize,
+    ) -> Result<(), BoxyError> {
+        if column_count == 0 {
+            return Err(BoxyError::ZeroColumns);
+        }
+        self.add_col_text_sgmt(text_align, column_count);
+        Ok(())
+    }
+
+    /// Adds a line to a single text segment like
+    /// [`add_text_line_indx`](Self::add_text_line_indx).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BoxyError::SegmentIndex`] if there is no segment `seg_index`,
+    /// [`BoxyError::NotSingle`] if it is columnar, and [`BoxyError::InvalidColor`] if `color`
+    /// isn't a hex color code.
+    pub fn try_add_text_line_indx(
+        &mut self,
+        data_string: &str,
+        color: &str,
+        seg_index: usize,
+    ) -> Result<(), BoxyError> {
+        check_single(self.seg_data(), seg_index)?;
+        check_color(color)?;
+        self.add_text_line_indx(data_string, color, seg_index);
+        Ok(())
+    }
+
+    /// Adds a line to a column of a columnar segment like
+    /// [`add_col_text_line_indx`](Self::add_col_text_line_indx).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BoxyError::SegmentIndex`] if there is no segment `seg_index`,
+    /// [`BoxyError::NotColumnar`] if it isn't columnar, [`BoxyError::ColumnIndex`] if it has
+    /// no column `col_index`, and [`BoxyError::InvalidColor`] if `color` isn't a hex color
+    /// code.
+    pub fn try_add_col_text_line_indx(
+        &mut self,
+        data_string: &str,
+        color: &str,
+        seg_index: usize,
+        col_index: usize,
+    ) -> Result<(), BoxyError> {
+        check_column(self.seg_data(), seg_index, col_index)?;
+        check_color(color)?;
+        self.add_col_text_line_indx(data_string, color, &seg_index, &col_index);
+        Ok(())
+    }
+
+    /// Adds a line to the last segment like [`add_text_line`](Self::add_text_line).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BoxyError::NoSegments`] if there is no segment yet,
+    /// [`BoxyError::NotSingle`] if the last one is columnar, and
+    /// [`BoxyError::InvalidColor`] if `color` isn't a hex color code.
+    pub fn try_add_text_line(&mut self, data_string: &str, color: &str) -> Result<(), BoxyError> {
+        let seg_index = last_segment(self.seg_data())?;
+        self.try_add_text_line_indx(data_string, color, seg_index)
+    }
+
+    /// Adds a line to a column of the last segment like
+    /// [`add_col_text_line`](Self::add_col_text_line).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BoxyError::NoSegments`] if there is no segment yet,
+    /// [`BoxyError::NotColumnar`] if the last one isn't columnar, [`BoxyError::ColumnIndex`]
+    /// if it has no column `col_index`, and [`BoxyError::InvalidColor`] if `color` isn't a
+    /// hex color code.
+    pub fn try_add_col_text_line(
+        &mut self,
+        data_string: &str,
+        color: &str,
+        col_index: usize,
+    ) -> Result<(), BoxyError> {
+        let seg_index = last_segment(self.seg_data())?;
+        self.try_add_col_text_line_indx(data_string, color, seg_index, col_index)
+    }
+
+    /// Sets the fixed width like [`set_width`](Self::set_width); 0 sizes the box to the
+    /// terminal again.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BoxyError::Width`] if `width` leaves no room for text between the borders
+    /// and the internal padding.
+    pub fn try_set_width(&mut self, width: usize) -> Result<(), BoxyError> {
+        check_width(width, &self.int_padding())?;
+        self.set_width(width);
+        Ok(())
+    }
+
+    /// Sets the border type like [`set_type`](Self::set_type).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BoxyError::Border`] if a [`BoxType::Custom`] has a glyph that isn't one
+    /// column wide.
+    pub fn try_set_type(&mut self, box_type: BoxType) -> Result<(), BoxyError> {
+        check_type(&box_type)?;
+        self.set_type(box_type);
+        Ok(())
+    }
+
+    /// Sets the divider style like [`set_divider_style`](Self::set_divider_style).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BoxyError::Border`] if a [`BoxType::Custom`] has a glyph that isn't one
+    /// column wide.
+    pub fn try_set_divider_style(&mut self, style: BoxType) -> Result<(), BoxyError> {
+        check_type(&style)?;
+        self.set_divider_style(style);
+        Ok(())
+    }
+
+    /// Sets the border color like [`set_color`](Self::set_color).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BoxyError::InvalidColor`] if `color` isn't a hex color code.
+    pub fn try_set_color(&mut self, color: &str) -> Result<(), BoxyError> {
+        check_color(color)?;
+        self.set_color(color);
+        Ok(())
+    }
+
+    /// Sets how the border is colored like [`set_border_paint`](Self::set_border_paint).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BoxyError::InvalidColor`] for the first color of `paint` that isn't a hex
+    /// color code.
+    pub fn try_set_border_paint(&mut self, paint: BorderPaint) -> Result<(), BoxyError> {
+        check_paint(&paint)?;
+        self.set_border_paint(paint);
+        Ok(())
+    }
+
+    /// Sets the drop shadow like [`set_shadow`](Self::set_shadow).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BoxyError::InvalidColor`] if the shadow color isn't a hex color code.
+    pub fn try_set_shadow(&mut self, shadow: Shadow) -> Result<(), BoxyError> {
+        check_color(&shadow.color)?;
+        self.set_shadow(shadow);
+        Ok(())
+    }
+
+    /// Sets the divider below a segment like [`set_divider`](Self::set_divider).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BoxyError::SegmentIndex`] if there is no segment `seg_index`, and
+    /// [`BoxyError::Border`] for a [`Divider::Styled`] custom style with a glyph that isn't
+    /// one column wide.
+    pub fn try_set_divider(&mut self, seg_index: usize, divider: Divider) -> Result<(), BoxyError> {
+        check_segment(self.seg_data(), seg_index)?;
+        check_divider(&divider)?;
+        self.set_divider(seg_index, divider);
+        Ok(())
+    }
+
+    /// Sets the column width ratios of a columnar segment like
+    /// [`set_segment_ratios`](Self::set_segment_ratios).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BoxyError::SegmentIndex`] if there is no segment `seg_index`,
+    /// [`BoxyError::NotColumnar`] if it isn't columnar, [`BoxyError::RatioCount`] if
+    /// there isn't one ratio per column, and [`BoxyError::ZeroRatios`] if they add up to 0.
+    pub fn try_set_segment_ratios(
+        &mut self,
+        seg_index: usize,
+        ratios: Vec<usize>,
+    ) -> Result<(), BoxyError> {
+        check_ratios(self.seg_data(), seg_index, &ratios)?;
+        self.set_segment_ratios(seg_index, ratios);
+        Ok(())
+    }
+}
+
+// Checks that `color` is a hex color code
+pub(crate) fn check_color(color: &str) -> Result<(), BoxyError> {
+    hex_color::HexColor::parse(color)
+        .map(|_| ())
+        .map_err(|_| BoxyError::InvalidColor(color.to_string()))
+}
+
+pub(crate) fn check_paint(paint: &BorderPaint) -> Result<(), BoxyError> {
+    match paint {
+        BorderPaint::Solid => Ok(()),
+        BorderPaint::Gradient { stops, .. } => stops.iter().try_for_each(|c| check_color(c)),
+        BorderPaint::Sides {
+            top,
+            right,
+            bottom,
+            left,
+        } => [top, right, bottom, left]
+            .into_iter()
+            .flatten()
+            .try_for_each(|c| check_color(c)),
+    }
+}
+
+pub(crate) fn check_type(box_type: &BoxType) -> Result<(), BoxyError> {
+    match box_type {
+        BoxType::Custom(set) => Ok(set.validate()?),
+        _ => Ok(()),
+    }
+}
+
+pub(crate) fn check_divider(divider: &Divider) -> Result<(), BoxyError> {
+    match divider {
+        Divider::Styled(style) => check_type(style),
+        _ => Ok(()),
+    }
+}
+
+pub(crate) fn check_segment(data: &[SegType], seg_index: usize) -> Result<&SegType, BoxyError> {
+    data.get(seg_index).ok_or(BoxyError::SegmentIndex {
+        index: seg_index,
+        count: data.len(),
+    })
+}
+
+pub(crate) fn last_segment(data: &[SegType]) -> Result<usize, BoxyError> {
+    data.len().checked_sub(1).ok_or(BoxyError::NoSegments)
+}
+
+pub(crate) fn check_single(data: &[SegType], seg_index: usize) -> Result<(), BoxyError> {
+    match check_segment(data, seg_index)? {
+        SegType::Single(_) => Ok(()),
+        SegType::Columnar(_) => Err(BoxyError::NotSingle(seg_index)),
+    }
+}
+
+pub(crate) fn check_column(
+    data: &[SegType],
+    seg_index: usize,
+    col_index: usize,
+) -> Result<(), BoxyError> {
+    match check_segment(data, seg_index)? {
+        SegType::Columnar(cols) if col_index < cols.len() => Ok(()),
+        SegType::Columnar(cols) => Err(BoxyError::ColumnIndex {
+            segment: seg_index,
+            index: col_index,
+            count: cols.len(),
+        }),
+        SegType::Single(_) => Err(BoxyError::NotColumnar(seg_index)),
+    }
+}
+
+pub(crate) fn check_ratios(
+    data: &[SegType],
+    seg_index: usize,
+    ratios: &[usize],
+) -> Result<(), BoxyError> {
+    match check_segment(data, seg_index)? {
+        SegType::Columnar(cols) if cols.len() != ratios.len() => Err(BoxyError::RatioCount {
+            segment: seg_index,
+            columns: cols.len(),
+            ratios: ratios.len(),
+        }),
+        SegType::Columnar(_) if ratios.iter().sum::<usize>() == 0 => {
+            Err(BoxyError::ZeroRatios(seg_index))
+        }
+        SegType::Columnar(_) => Ok(()),
+        SegType::Single(_) => Err(BoxyError::NotColumnar(seg_index)),
+    }
+}
+
+// A fixed width needs room for both borders, the internal padding, the space text keeps
+// from each border and one column of text; 0 means no fixed width
+pub(crate) fn check_width(width: usize, int_padding: &BoxPad) -> Result<(), BoxyError> {
+    let min = int_padding.lr() + 5;
+    match width {
+        0 => Ok(()),
+        width if width < min => Err(BoxyError::Width { width, min }),
+        _ => Ok(()),
+    }
+}
@@ -0,0 +1,65 @@
+//! HTML export, available with the `html` cargo feature.
+
+use std::fmt::Write;
+
+use crate::boxer::Boxy;
+use crate::export::escape;
+use crate::paint::{Ink, hex};
+
+impl Boxy {
+    /// Renders the text box as an HTML `<pre>` block.
+    ///
+    /// Each line of [`render`](Self::render) becomes one line of the block, character for
+    /// character: border and text colors become `<span style="color:…">`, and shadows drawn
+    /// with [`ShadowStyle::Dim`](crate::paint::ShadowStyle::Dim) become
+    /// `background-color`. Text is HTML-escaped, so content can't inject markup. The
+    /// block carries a `boxy` class for styling; use a monospace font with box-drawing
+    /// glyphs for the borders to line up.
+    ///
+    /// Colors are always written, whatever the [`ColorMode`](crate::paint::ColorMode).
+    ///
+    /// Available with the `html` cargo feature.
+    ///
+    /// # Arguments
+    ///
+    /// * `term_width` - The total column width to render into, as for [`render`](Self::render)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use boxy_cli::prelude::*;
+    ///
+    /// let mut b = Boxy::new(BoxType::Single, "#00ffff");
+    /// b.add_text_sgmt("<3 & more", "#ff0000", BoxAlign::Left);
+    /// b.set_width(20);
+    ///
+    /// let html = b.render_html(40);
+    /// assert!(html.starts_with("<pre class=\"boxy\">"));
+    /// assert!(html.contains("<span style=\"color:#ff0000\">&lt;3 &amp; more</span>"));
+    /// ```
+    pub fn render_html(&self, term_width: usize) -> String {
+        let mut out = String::from("<pre class=\"boxy\">");
+        for (i, line) in self.runs(term_width).iter().enumerate() {
+            if i > 0 {
+                out.push('\n');
+            }
+            for run in line {
+                let text = escape(&run.text);
+                let _ = match run.ink {
+                    Ink::Plain => write!(out, "{}", text),
+                    Ink::Fg(color) => {
+                        write!(out, "<span style=\"color:{}\">{}</span>", hex(color), text)
+                    }
+                    Ink::Bg(color) => write!(
+                        out,
+                        "<span style=\"background-color:{}\">{}</span>",
+                        hex(color),
+                        text
+                    ),
+                };
+            }
+        }
+        out.push_str("</pre>");
+        out
+    }
+}
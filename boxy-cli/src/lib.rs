@@ -8,9 +8,13 @@
 //!
 //! ## Features
 //!
-//! - **9 border styles** — classic ASCII, single, double, bold, rounded, and more
-//! - **True-color support** — hex color codes for both borders and per-line text
-//! - **Multi-segment boxes** — stack multiple sections separated by smart dividers
+//! - **16 border styles** — single, double, bold, rounded, dashed, dotted, block, strict
+//!   ASCII, and more, plus
+//!   user-defined glyph sets via [`BorderSet`] and [`BoxType::Custom`]
+//! - **True-color support** — hex color codes for both borders and per-line text, with
+//!   border gradients and per-side colors (see [`BorderPaint`])
+//! - **Multi-segment boxes** — stack multiple sections separated by smart dividers, styled,
+//!   labeled or left out per boundary (see [`Divider`])
 //! - **Columnar layouts** — side-by-side columns inside a single box, with per-segment
 //!   ratio control and correct `┼`/`┬`/`┴` junction characters where column boundaries meet
 //! - **Word wrapping** — automatic wrapping to terminal width with internal padding awareness
@@ -18,7 +22,30 @@
 //! - **Terminal-aware sizing** — auto-sizes to terminal width, or use a fixed width
 //! - **Two APIs** — imperative ([`Boxy`]) and fluent builder
 //!   ([`BoxyBuilder`])
+//! - **Fallible API** — `try_` methods and [`BoxyBuilder::try_build`] return a
+//!   [`BoxyError`] instead of panicking or printing warnings
+//! - **Layout diagnostics** — [`Boxy::diagnose`] reports text a narrow terminal breaks or
+//!   leaves out, as [`LayoutWarning`]s
 //! - **Macro support** — [`boxy!`] for quick one-liner (Work in Progress)
+//! - **Streaming** — draw a box line by line as its content arrives, through
+//!   [`io::Write`](std::io::Write) (see [`BoxWriter`])
+//! - **Live boxes** — status boxes redrawn in place as their content changes (see
+//!   [`LiveBox`])
+//! - **ASCII fallback** — opt in to plain `+-|` borders when the locale or terminal can't
+//!   display UTF-8 (see [`AsciiFallback`])
+//! - **Theme files** — load border styles and themes from TOML or JSON (`config` feature,
+//!   see the `theme` module)
+//! - **Serde support** — serialize boxes as specs and render them elsewhere (`serde`
+//!   feature, see the `spec` module)
+//! - **HTML export** — the same box as a colored `<pre>` block for web pages (`html`
+//!   feature, see `Boxy::render_html`)
+//! - **SVG export** — images of boxes for READMEs and docs, generated from code (`svg`
+//!   feature, see the `svg` module)
+//! - **Markdown export** — paragraphs, headings and GFM tables for PR comments and wikis
+//!   (`markdown` feature, see the `markdown` module)
+//! - **`boxy` command** — a binary drawing boxes from flags, stdin, JSON/TOML layout
+//!   files or a command's output, for shell scripts (`cli` feature, `cargo install
+//!   boxy-cli --features cli`)
 //!
 //! ## Known Limitations
 //!
@@ -75,9 +102,27 @@
 #[allow(dead_code)]
 pub mod boxer;
 mod constructs;
+pub mod diagnose;
+pub mod error;
+#[cfg(any(feature = "html", feature = "svg"))]
+mod export;
+#[cfg(feature = "html")]
+mod html;
+pub mod live;
 mod macros;
+#[cfg(feature = "markdown")]
+pub mod markdown;
+pub mod paint;
 pub mod prelude;
+#[cfg(feature = "serde")]
+pub mod spec;
+pub mod stream;
+#[cfg(feature = "svg")]
+pub mod svg;
 pub(crate) mod templates;
+pub mod term;
+#[cfg(feature = "config")]
+pub mod theme;
 
 // crate tests
 mod tests;
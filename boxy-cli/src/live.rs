@@ -0,0 +1,349 @@
+//! Boxes redrawn in place as their content changes.
+//!
+//! A [`LiveBox`] owns a [`Boxy`] and draws it again on every [`draw`](LiveBox::draw).
+//! On a terminal, each frame replaces the one before: the cursor moves back up over the
+//! lines drawn last time and only the lines that changed are written again, so a status
+//! box updating every second stays in one place instead of scrolling. Anywhere else, such
+//! as a pipe or a log file, frames are appended one after another.
+//!
+//! A live box on stdout follows the terminal size, as the box's size provider reports it
+//! (see [`Boxy::set_size_provider`]): on Unix a `SIGWINCH` tells it when the terminal was
+//! resized, elsewhere it asks for the size before every frame. After a resize
+//! the next frame is rendered at the new width, replacing the old one entirely. A loop
+//! waiting for work can call [`resized`](LiveBox::resized) to redraw right away.
+//!
+//! ```no_run
+//! use boxy_cli::prelude::*;
+//!
+//! let mut status = Boxy::new(BoxType::Rounded, "#00ffff");
+//! status.add_text_sgmt("Deploying", "#ffffff", BoxAlign::Center);
+//!
+//! let mut live = LiveBox::stdout(status);
+//! for done in 0..=10 {
+//!     let b = live.boxy_mut();
+//!     b.clear_segments();
+//!     b.add_text_sgmt("Deploying", "#ffffff", BoxAlign::Center);
+//!     b.add_text_sgmt(&format!("{} of 10 hosts", done), "#00ff00", BoxAlign::Left);
+//!     live.draw()?;
+//!     std::thread::sleep(std::time::Duration::from_secs(1));
+//! }
+//! # Ok::<(), std::io::Error>(())
+//! ```
+
+use std::fmt::{self, Write as _};
+use std::io::{self, IsTerminal, Write};
+
+use unicode_width::UnicodeWidthStr;
+
+use crate::boxer::{Boxy, FALLBACK_WIDTH};
+#[cfg(unix)]
+use crate::term::ResizeWatch;
+use crate::term::TerminalSize;
+
+/// A box drawn again in place every time its content changes.
+///
+/// Change the box through [`boxy_mut`](Self::boxy_mut) between frames, e.g. with
+/// [`Boxy::clear_segments`] and the `add_*` methods, then call [`draw`](Self::draw).
+///
+/// In place, the writer is expected to be a terminal that understands ANSI cursor
+/// movement, with the cursor at the start of a line when the first frame is drawn. Only the
+/// rows still on screen can be redrawn: a box taller than the terminal scrolls.
+/// Otherwise (see [`set_in_place`](Self::set_in_place)) every frame that differs from the
+/// last one is appended in full.
+///
+/// With a size source (see [`set_size_source`](Self::set_size_source)), every frame is
+/// rendered at the terminal's current width. When the width changed since the last frame,
+/// the old frame is cleared, including what the terminal made of its lines when it rewrapped
+/// them, and the new one drawn whole.
+pub struct LiveBox<W: Write> {
+    boxy: Boxy,
+    out: W,
+    term_width: usize,
+    in_place: bool,
+    size: Option<Box<dyn TerminalSize>>,
+    // without a size source, ask the box's own size provider
+    sized_by_box: bool,
+    // without it, the size is asked for before every frame
+    #[cfg(unix)]
+    watch: Option<ResizeWatch>,
+    // the lines of the last frame, as they are on screen, and the width it was drawn at
+    drawn: Vec<String>,
+    drawn_width: usize,
+}
+
+impl LiveBox<io::Stdout> {
+    /// Creates a live box on stdout, sized to the terminal.
+    ///
+    /// The width comes from the box's size provider (see [`Boxy::set_size_provider`]), or
+    /// as [`Detect`](crate::term::Detect) finds it, and is 80 columns if it can't be told.
+    /// Frames are redrawn in place, following resizes, when stdout is a terminal, and
+    /// appended otherwise.
+    pub fn stdout(boxy: Boxy) -> Self {
+        let stdout = io::stdout();
+        let in_place = stdout.is_terminal();
+        let term_width = boxy.term_size().map_or(FALLBACK_WIDTH, |s| s.cols);
+        let mut live = LiveBox::new(boxy, stdout, term_width);
+        live.set_in_place(in_place);
+        if in_place {
+            live.sized_by_box = true;
+            #[cfg(unix)]
+            {
+                live.watch = ResizeWatch::new();
+            }
+        }
+        live
+    }
+}
+
+impl<W: Write + fmt::Debug> fmt::Debug for LiveBox<W> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LiveBox")
+            .field("boxy", &self.boxy)
+            .field("out", &self.out)
+            .field("term_width", &self.term_width)
+            .field("in_place", &self.in_place)
+            .field("drawn", &self.drawn)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<W: Write> LiveBox<W> {
+    /// Creates a live box drawing into `out`, redrawn in place.
+    ///
+    /// Nothing is written until the first [`draw`](Self::draw).
+    ///
+    /// # Arguments
+    ///
+    /// * `boxy` - The box to draw
+    /// * `out` - The terminal to draw into
+    /// * `term_width` - The total column width to render into, as for [`Boxy::render`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use boxy_cli::prelude::*;
+    ///
+    /// let mut b = Boxy::new(BoxType::Single, "#00ffff");
+    /// b.add_text_sgmt("Waiting", "#ffffff", BoxAlign::Left);
+    /// b.set_color_mode(ColorMode::Never);
+    ///
+    /// let mut live = LiveBox::new(b, Vec::new(), 30);
+    /// live.draw()?;
+    /// live.boxy_mut().clear_segments();
+    /// live.boxy_mut().add_text_sgmt("Done", "#ffffff", BoxAlign::Left);
+    /// live.draw()?;
+    ///
+    /// // up over the 3 rows, then only the middle one is written again
+    /// let out = String::from_utf8(live.into_inner()).unwrap();
+    /// assert!(out.ends_with("\x1b[3A\x1b[1B\x1b[2K│ Done                       │\n\x1b[1B"));
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    pub fn new(boxy: Boxy, out: W, term_width: usize) -> Self {
+        LiveBox {
+            boxy,
+            out,
+            term_width,
+            in_place: true,
+            size: None,
+            sized_by_box: false,
+            #[cfg(unix)]
+            watch: None,
+            drawn: Vec::new(),
+            drawn_width: term_width,
+        }
+    }
+
+    /// Chooses between redrawing frames in place (`true`, the default for
+    /// [`new`](Self::new)) and appending them (`false`), for output that isn't a terminal.
+    pub fn set_in_place(&mut self, in_place: bool) {
+        self.in_place = in_place;
+    }
+
+    /// Sets the total column width the next frames are rendered into.
+    ///
+    /// A size source, if there is one, overrides it as soon as it reports a size.
+    pub fn set_term_width(&mut self, term_width: usize) {
+        self.term_width = term_width;
+    }
+
+    /// Renders every frame at the width `source` reports, instead of a fixed one.
+    ///
+    /// The source is asked before every frame, and by [`resized`](Self::resized). When it
+    /// has no answer, the last known width is kept. Without a source,
+    /// [`stdout`](Self::stdout) asks the box's size provider when stdout is a terminal.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::cell::Cell;
+    /// use std::rc::Rc;
+    /// use boxy_cli::prelude::*;
+    /// use boxy_cli::term::Size;
+    ///
+    /// let cols = Rc::new(Cell::new(40));
+    /// let mut live = LiveBox::new(Boxy::new(BoxType::Single, "#00ffff"), Vec::new(), 80);
+    /// live.set_size_source({
+    ///     let cols = Rc::clone(&cols);
+    ///     move || Some(Size { cols: cols.get(), rows: 24 })
+    /// });
+    /// live.draw()?;
+    ///
+    /// cols.set(30);
+    /// assert!(live.resized());
+    /// live.draw()?;
+    /// assert!(!live.resized());
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    pub fn set_size_source(&mut self, source: impl TerminalSize + 'static) {
+        self.size = Some(Box::new(source));
+    }
+
+    /// Whether the width changed since the last frame, so the box should be drawn again.
+    ///
+    /// On Unix, a live box on stdout only asks for the size after a `SIGWINCH`, which makes
+    /// this cheap enough to call in a tight loop.
+    pub fn resized(&mut self) -> bool {
+        self.measure();
+        self.resized_since_drawn()
+    }
+
+    /// The box being drawn.
+    pub fn boxy(&self) -> &Boxy {
+        &self.boxy
+    }
+
+    /// The box being drawn, to change before the next frame.
+    pub fn boxy_mut(&mut self) -> &mut Boxy {
+        &mut self.boxy
+    }
+
+    /// Draws the box as it is now, over the previous frame, and flushes.
+    ///
+    /// In place, unchanged lines are skipped and rows left over from a taller previous
+    /// frame are cleared; the cursor ends up on the line below the box. After a resize the
+    /// whole frame is drawn again. Appending, a frame identical to the last one is not
+    /// written again.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error from writing or flushing.
+    pub fn draw(&mut self) -> io::Result<()> {
+        self.measure();
+        let lines = self.boxy.render(self.term_width);
+        let frame = match self.in_place {
+            true if self.resized_since_drawn() => self.replace(&lines),
+            true => self.redraw(&lines),
+            false if lines == self.drawn => return Ok(()),
+            false => lines.iter().map(|line| format!("{}\n", line)).collect(),
+        };
+        // one write for the whole frame, so it doesn't flicker
+        self.out.write_all(frame.as_bytes())?;
+        self.out.flush()?;
+        self.drawn = lines;
+        self.drawn_width = self.term_width;
+        Ok(())
+    }
+
+    /// The underlying writer.
+    pub fn get_ref(&self) -> &W {
+        &self.out
+    }
+
+    /// Gives back the underlying writer, leaving the last frame where it is.
+    pub fn into_inner(self) -> W {
+        self.out
+    }
+
+    // Takes the terminal width from the size source, or the box's size provider on
+    // stdout, if it may have changed
+    fn measure(&mut self) {
+        if self.size.is_none() && !self.sized_by_box {
+            return;
+        }
+        #[cfg(unix)]
+        if let Some(watch) = &self.watch
+            && !watch.take()
+        {
+            return;
+        }
+        let size = match &self.size {
+            Some(source) => source.size(),
+            None => self.boxy.term_size(),
+        };
+        if let Some(size) = size {
+            self.term_width = size.cols;
+        }
+    }
+
+    fn resized_since_drawn(&self) -> bool {
+        !self.drawn.is_empty() && self.term_width != self.drawn_width
+    }
+
+    // The escapes and text clearing the frame on screen and drawing `lines` instead
+    fn replace(&self, lines: &[String]) -> String {
+        let mut frame = String::new();
+        // a terminal rewraps lines wider than itself onto several rows
+        let cols = self.term_width.max(1);
+        let rows: usize = self
+            .drawn
+            .iter()
+            .map(|line| visible_width(line).div_ceil(cols).max(1))
+            .sum();
+        if rows > 0 {
+            let _ = write!(frame, "\x1b[{}A", rows);
+        }
+        // everything from here to the end of the screen
+        frame.push_str("\x1b[J");
+        for line in lines {
+            let _ = writeln!(frame, "{}", line);
+        }
+        frame
+    }
+
+    // The escapes and text turning the frame on screen into `lines`
+    fn redraw(&self, lines: &[String]) -> String {
+        let mut frame = String::new();
+        if !self.drawn.is_empty() {
+            let _ = write!(frame, "\x1b[{}A", self.drawn.len());
+        }
+        let mut skipped = 0;
+        for (i, line) in lines.iter().enumerate() {
+            if self.drawn.get(i) == Some(line) {
+                skipped += 1;
+                continue;
+            }
+            if skipped > 0 {
+                let _ = write!(frame, "\x1b[{}B", skipped);
+                skipped = 0;
+            }
+            let _ = writeln!(frame, "\x1b[2K{}", line);
+        }
+        if skipped > 0 {
+            let _ = write!(frame, "\x1b[{}B", skipped);
+        }
+        // rows of a taller previous frame
+        let leftover = self.drawn.len().saturating_sub(lines.len());
+        if leftover > 0 {
+            for _ in 0..leftover {
+                frame.push_str("\x1b[2K\n");
+            }
+            let _ = write!(frame, "\x1b[{}A", leftover);
+        }
+        frame
+    }
+}
+
+// Columns a painted line takes up on screen, leaving out its color escapes
+fn visible_width(line: &str) -> usize {
+    let mut text = String::with_capacity(line.len());
+    let mut chars = line.chars();
+    while let Some(c) = chars.next() {
+        if c == '\x1b' {
+            // SGR sequences end at their `m`
+            chars.by_ref().find(|&c| c == 'm');
+        } else {
+            text.push(c);
+        }
+    }
+    UnicodeWidthStr::width(text.as_str())
+}
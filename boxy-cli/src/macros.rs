@@ -4,7 +4,8 @@
 ///
 /// Currently, it has the following accepting fields:
 ///
-///  - **type** - takes a [BoxType](crate::constructs::BoxType) enum
+///  - **type** - takes a [BoxType](crate::constructs::BoxType) enum, custom ones included, or a
+///    style name such as `"rounded"`
 ///
 ///  - **color** - takes a hex code for a color
 ///
@@ -33,17 +34,29 @@
 /// ! The padding values here are taken to be for uniform padding on all sides.
 #[macro_export]
 macro_rules! boxy {
+    // one rule per field, so each value is only turned into the type its field takes
+    (@set $boxy:ident, type, $value:expr) => {
+        $boxy.set_type($crate::boxer::resolve_type($value))
+    };
+    (@set $boxy:ident, color, $value:expr) => {
+        $boxy.set_color(&$crate::boxer::resolve_col($value.to_string()))
+    };
+    (@set $boxy:ident, internal_pad, $value:expr) => {
+        $boxy.set_int_padding($crate::boxer::resolve_pad($value.to_string()))
+    };
+    (@set $boxy:ident, external_pad, $value:expr) => {
+        $boxy.set_ext_padding($crate::boxer::resolve_pad($value.to_string()))
+    };
+    (@set $boxy:ident, alignment, $value:expr) => {
+        $boxy.set_align($crate::boxer::resolve_align($value.to_string()))
+    };
+    (@set $boxy:ident, $key:ident, $value:expr) => {
+        panic!("Unknown field: {}", stringify!($key))
+    };
     ($($key:ident: $value:expr),* $(,)?) => {{
         let mut boxy = $crate::boxer::Boxy::default();
         $(
-            match stringify!($key) {
-                "type" => boxy.set_type($crate::boxer::resolve_type($value.to_string())),
-                "color" => boxy.set_color(&$crate::boxer::resolve_col($value.to_string())),
-                "internal_pad" => boxy.set_int_padding($crate::boxer::resolve_pad($value.to_string())),
-                "external_pad" => boxy.set_ext_padding($crate::boxer::resolve_pad($value.to_string())),
-                "alignment" => boxy.set_align($crate::boxer::resolve_align($value.to_string())),
-                _ => panic!("Unknown field: {}", stringify!($key)),
-            }
+            $crate::boxy!(@set boxy, $key, $value);
         )*
         boxy
     }};
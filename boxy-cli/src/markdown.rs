@@ -0,0 +1,299 @@
+//! Markdown export, for PR comments, wikis and anywhere else box glyphs look out of place.
+//!
+//! Available with the `markdown` cargo feature. [`Boxy::render_markdown`] rebuilds the
+//! box's content as GitHub-flavored Markdown instead of drawing it:
+//!
+//! - plain segments become paragraphs, one line per line of text. Centered and
+//!   right-aligned segments are wrapped in `<div align="…">`, which GitHub and most wikis
+//!   honor
+//! - columnar segments become tables, with the segment's alignment as the column markers
+//!   (`:---`, `:---:`, `---:`)
+//! - labeled dividers ([`Divider::Labeled`]) become headings for
+//!   the segment after them; the first segment can be made a heading, too
+//!
+//! Colors are dropped, unless [`MarkdownOptions::emphasize`] maps them to emphasis.
+//!
+//! ```
+//! use boxy_cli::prelude::*;
+//!
+//! let b = Boxy::builder()
+//!     .add_segment("Build report", "#ffffff", BoxAlign::Center)
+//!     .add_col_segment(BoxAlign::Left, 2)
+//!     .add_col_line("Tests", "#ffffff", 0)
+//!     .add_col_line("412 passed", "#00ff00", 0)
+//!     .add_col_line("Lints", "#ffffff", 1)
+//!     .add_col_line("3 warnings", "#ffff00", 1)
+//!     .build();
+//!
+//! let options = MarkdownOptions::default()
+//!     .title_level(Some(2))
+//!     .emphasize("#ffff00", Emphasis::Bold);
+//! assert_eq!(
+//!     b.render_markdown(&options),
+//!     "<div align=\"center\">\n\n## Build report\n\n</div>\n\n\
+//!      | Tests | Lints |\n\
+//!      | :--- | :--- |\n\
+//!      | 412 passed | **3 warnings** |\n"
+//! );
+//! ```
+
+use colored::Color;
+
+use crate::boxer::Boxy;
+use crate::constructs::{BoxAlign, Divider, SegColor, SegType};
+use crate::error::check_color;
+
+/// Markdown emphasis a text color can be mapped to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Emphasis {
+    /// `**bold**`
+    Bold,
+    /// `_italic_`
+    Italic,
+    /// `***bold italic***`
+    BoldItalic,
+    /// `~~strikethrough~~`
+    Strikethrough,
+    /// `` `code` ``
+    Code,
+}
+
+impl Emphasis {
+    fn wrap(self, text: &str) -> String {
+        match self {
+            Emphasis::Bold => format!("**{}**", text),
+            Emphasis::Italic => format!("_{}_", text),
+            Emphasis::BoldItalic => format!("***{}***", text),
+            Emphasis::Strikethrough => format!("~~{}~~", text),
+            Emphasis::Code => {
+                // a fence longer than any backtick run inside, padded when touching one
+                let longest = text.split(|c| c != '`').map(str::len).max().unwrap_or(0);
+                let fence = "`".repeat(longest + 1);
+                if text.starts_with('`') || text.ends_with('`') {
+                    format!("{fence} {text} {fence}")
+                } else {
+                    format!("{fence}{text}{fence}")
+                }
+            }
+        }
+    }
+}
+
+/// Options for [`Boxy::render_markdown`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct MarkdownOptions {
+    /// Heading level (1–6) for the first segment when it is a single line of plain text,
+    /// or `None` to keep it a paragraph
+    pub title_level: Option<u8>,
+    /// Heading level (1–6) for the labels of [`Divider::Labeled`]
+    pub label_level: u8,
+    /// Wraps centered and right-aligned paragraphs in `<div align="…">`
+    pub align_hints: bool,
+    /// Treats the first line of each column as the table header. Otherwise the header row
+    /// is left empty and every line is a body row.
+    pub table_header: bool,
+    /// Hex colors and the emphasis lines in that color get; other colors are dropped, and
+    /// so are entries whose color isn't a valid hex code
+    pub emphasis: Vec<(String, Emphasis)>,
+}
+
+impl Default for MarkdownOptions {
+    fn default() -> Self {
+        Self {
+            title_level: None,
+            label_level: 3,
+            align_hints: true,
+            table_header: true,
+            emphasis: Vec::new(),
+        }
+    }
+}
+
+impl MarkdownOptions {
+    /// Sets the heading level for a one-line first segment, or `None` for a paragraph.
+    pub fn title_level(mut self, level: Option<u8>) -> Self {
+        self.title_level = level;
+        self
+    }
+
+    /// Sets the heading level for divider labels.
+    pub fn label_level(mut self, level: u8) -> Self {
+        self.label_level = level;
+        self
+    }
+
+    /// Turns the `<div align="…">` wrappers for centered and right-aligned text on or off.
+    pub fn align_hints(mut self, on: bool) -> Self {
+        self.align_hints = on;
+        self
+    }
+
+    /// Sets whether the first line of each column is the table header.
+    pub fn table_header(mut self, on: bool) -> Self {
+        self.table_header = on;
+        self
+    }
+
+    /// Renders lines in the hex `color` with `emphasis`.
+    pub fn emphasize(mut self, color: &str, emphasis: Emphasis) -> Self {
+        self.emphasis.push((color.to_string(), emphasis));
+        self
+    }
+}
+
+impl Boxy {
+    /// Renders the content of the text box as GitHub-flavored Markdown.
+    ///
+    /// No box is drawn: segments become paragraphs, headings and tables as described in
+    /// the [`markdown`](crate::markdown) module. Markdown syntax in the text is escaped,
+    /// so it comes out as written. The output ends with a newline.
+    ///
+    /// Available with the `markdown` cargo feature.
+    ///
+    /// # Arguments
+    ///
+    /// * `options` - Headings, alignment hints, table headers and color emphasis
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use boxy_cli::prelude::*;
+    ///
+    /// let mut b = Boxy::new(BoxType::Single, "#00ffff");
+    /// b.add_text_sgmt("All *good*", "#ffffff", BoxAlign::Left);
+    /// b.add_text_sgmt("v1.2.0", "#ffffff", BoxAlign::Left);
+    /// b.set_divider(0, Divider::Labeled("Version".to_string()));
+    ///
+    /// assert_eq!(
+    ///     b.render_markdown(&MarkdownOptions::default()),
+    ///     "All \\*good\\*\n\n### Version\n\nv1.2.0\n"
+    /// );
+    /// ```
+    pub fn render_markdown(&self, options: &MarkdownOptions) -> String {
+        let emphasis: Vec<(Color, Emphasis)> = options
+            .emphasis
+            .iter()
+            .filter(|(hex, _)| check_color(hex).is_ok())
+            .map(|(hex, emphasis)| (SegColor::parse_hexcolor(hex), *emphasis))
+            .collect();
+        let styled = |text: &str, color: &Color, in_table: bool| {
+            match emphasis.iter().find(|(c, _)| c == color) {
+                // code spans show backslashes literally, so their text is left as is. In a
+                // table, GFM still ends the cell at a bare `|`, even inside a code span
+                Some((_, Emphasis::Code)) if !text.is_empty() && in_table => {
+                    Emphasis::Code.wrap(text).replace('|', "\\|")
+                }
+                Some((_, Emphasis::Code)) if !text.is_empty() => Emphasis::Code.wrap(text),
+                Some((_, emphasis)) if !text.is_empty() => emphasis.wrap(&escape(text)),
+                _ => escape(text),
+            }
+        };
+
+        let mut blocks = Vec::new();
+        for (i, (seg, colors, align)) in self.segments().enumerate() {
+            if i > 0
+                && let Divider::Labeled(label) = &self.dividers()[i - 1]
+            {
+                blocks.push(heading(options.label_level, &escape(label)));
+            }
+            let block = match (seg, colors) {
+                (SegType::Single(lines), SegColor::Single(colors)) => {
+                    let lines: Vec<String> = lines
+                        .iter()
+                        .zip(colors)
+                        .map(|(line, color)| styled(line, color, false))
+                        .collect();
+                    match options.title_level {
+                        Some(level) if i == 0 && lines.len() == 1 => heading(level, &lines[0]),
+                        // a backslash at the end of a line is a hard line break
+                        _ => lines.join("\\\n"),
+                    }
+                }
+                (SegType::Columnar(cols), SegColor::Columnar(colors)) => {
+                    let cols: Vec<Vec<String>> = cols
+                        .iter()
+                        .zip(colors)
+                        .map(|(col, colors)| {
+                            col.iter()
+                                .zip(colors)
+                                .map(|(line, color)| styled(line, color, true))
+                                .collect()
+                        })
+                        .collect();
+                    blocks.push(table(&cols, align, options.table_header));
+                    continue;
+                }
+                _ => continue,
+            };
+            if block.is_empty() {
+                continue;
+            }
+            blocks.push(match align {
+                BoxAlign::Left => block,
+                _ if !options.align_hints => block,
+                BoxAlign::Center => format!("<div align=\"center\">\n\n{}\n\n</div>", block),
+                BoxAlign::Right => format!("<div align=\"right\">\n\n{}\n\n</div>", block),
+            });
+        }
+        let mut out = blocks.join("\n\n");
+        out.push('\n');
+        out
+    }
+}
+
+fn heading(level: u8, text: &str) -> String {
+    format!("{} {}", "#".repeat(level.clamp(1, 6) as usize), text)
+}
+
+// A GFM table with one column per box column; shorter columns get empty cells
+fn table(cols: &[Vec<String>], align: &BoxAlign, header: bool) -> String {
+    let rows = cols.iter().map(Vec::len).max().unwrap_or(0);
+    let cell = |col: &Vec<String>, row: usize| col.get(row).cloned().unwrap_or_default();
+    let row = |cells: Vec<String>| format!("| {} |", cells.join(" | "));
+    let marker = match align {
+        BoxAlign::Left => ":---",
+        BoxAlign::Center => ":---:",
+        BoxAlign::Right => "---:",
+    };
+
+    let mut out = Vec::new();
+    let body_start = if header && rows > 0 {
+        out.push(row(cols.iter().map(|c| cell(c, 0)).collect()));
+        1
+    } else {
+        out.push(row(vec![String::new(); cols.len()]));
+        0
+    };
+    out.push(row(vec![marker.to_string(); cols.len()]));
+    for r in body_start..rows {
+        out.push(row(cols.iter().map(|c| cell(c, r)).collect()));
+    }
+    out.join("\n")
+}
+
+// Backslash-escapes Markdown syntax, and the characters that would start a block
+// (list markers, setext underlines) at the beginning of a line
+fn escape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        if matches!(
+            c,
+            '\\' | '`' | '*' | '_' | '[' | ']' | '<' | '>' | '|' | '~' | '#'
+        ) {
+            out.push('\\');
+        }
+        out.push(c);
+    }
+    // `- item`, `+ item`, `1. item` and `1) item` would become lists, `---` and `===` headings
+    match out.chars().next() {
+        Some('-' | '+' | '=') => out.insert(0, '\\'),
+        Some(c) if c.is_ascii_digit() => {
+            let digits = out.chars().take_while(char::is_ascii_digit).count();
+            if matches!(out[digits..].chars().next(), Some('.' | ')')) {
+                out.insert(digits, '\\');
+            }
+        }
+        _ => {}
+    }
+    out
+}
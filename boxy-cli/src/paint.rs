@@ -0,0 +1,553 @@
+//! Border coloring and effects: gradients, per-side colors and drop shadows.
+//!
+//! Boxes are laid out as lines of spans first, so every border glyph's position in the
+//! box is known before any color is applied. The [`BorderPaint`] then picks a color for
+//! each glyph, a [`Shadow`] is added around the finished frame, and the [`ColorMode`]
+//! decides whether any escape codes are written at all.
+
+use colored::{Color, Colorize};
+use unicode_width::UnicodeWidthStr;
+
+use crate::constructs::SegColor;
+
+/// Whether rendered output contains ANSI color escape codes.
+///
+/// # Examples
+///
+/// ```
+/// use boxy_cli::prelude::*;
+///
+/// let mut b = Boxy::new(BoxType::Single, "#00ffff");
+/// b.set_color_mode(ColorMode::Never);
+/// b.add_text_sgmt("Golden", "#ffffff", BoxAlign::Left);
+/// assert!(b.render(20).iter().all(|line| !line.contains('\x1b')));
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum ColorMode {
+    /// Left to the `colored` crate, which turns color off when stdout is not a terminal or
+    /// `NO_COLOR` / `CLICOLOR` ask it to
+    #[default]
+    Auto,
+    /// Always colored, in 24-bit true color, whatever the environment
+    Always,
+    /// Never colored: borders, layout and text only. The output is the same
+    /// byte for byte in every environment.
+    Never,
+}
+
+impl ColorMode {
+    // The run with its color escapes, if any
+    fn apply(self, run: &Run) -> String {
+        let text = run.text.as_str();
+        match (self, run.ink) {
+            (ColorMode::Never, _) | (_, Ink::Plain) => text.to_string(),
+            (ColorMode::Auto, Ink::Fg(color)) => text.color(color).to_string(),
+            (ColorMode::Auto, Ink::Bg(color)) => text.on_color(color).to_string(),
+            (ColorMode::Always, ink) => {
+                let code = match ink {
+                    Ink::Fg(Color::TrueColor { r, g, b }) => format!("38;2;{r};{g};{b}"),
+                    Ink::Bg(Color::TrueColor { r, g, b }) => format!("48;2;{r};{g};{b}"),
+                    Ink::Fg(color) => color.to_fg_str().into_owned(),
+                    Ink::Bg(color) => color.to_bg_str().into_owned(),
+                    Ink::Plain => unreachable!(),
+                };
+                format!("\x1b[{code}m{text}\x1b[0m")
+            }
+        }
+    }
+}
+
+// How a run of text is colored
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum Ink {
+    Plain,
+    Fg(Color),
+    Bg(Color),
+}
+
+// A painted stretch of a line, ready for any output format
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Run {
+    pub(crate) text: String,
+    pub(crate) ink: Ink,
+}
+
+/// The direction a border gradient runs in.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum GradientDirection {
+    /// From the left edge to the right edge
+    #[default]
+    Horizontal,
+    /// From the top edge to the bottom edge
+    Vertical,
+    /// Clockwise around the frame, starting and ending at the top-left corner
+    Perimeter,
+}
+
+/// How the border of a box is colored.
+///
+/// The default, [`Solid`](BorderPaint::Solid), draws every border glyph in the box color set
+/// with [`Boxy::new`](crate::boxer::Boxy::new) or [`set_color`](crate::boxer::Boxy::set_color).
+/// Colors are hex strings, like everywhere else in the crate; invalid ones fall back to white
+/// with a warning on stderr. When color output is off, borders are drawn uncolored whatever
+/// the paint.
+///
+/// # Examples
+///
+/// ```
+/// use boxy_cli::prelude::*;
+///
+/// let mut banner = Boxy::new(BoxType::Bold, "#ffffff");
+/// banner.set_border_paint(BorderPaint::Gradient {
+///     direction: GradientDirection::Horizontal,
+///     stops: vec!["#ff0080".to_string(), "#7928ca".to_string(), "#0070f3".to_string()],
+/// });
+/// banner.add_text_sgmt("Release 2.0", "#ffffff", BoxAlign::Center);
+/// banner.display();
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(rename_all = "snake_case", deny_unknown_fields)
+)]
+pub enum BorderPaint {
+    /// Every glyph in the box color
+    #[default]
+    Solid,
+    /// A gradient through two or more colors. Dividers and column separators inside the
+    /// box follow the gradient too. A single stop paints the whole border in that color.
+    Gradient {
+        /// The direction the gradient runs in
+        direction: GradientDirection,
+        /// Hex colors, spaced evenly from start to end
+        stops: Vec<String>,
+    },
+    /// Separate colors for each side; sides left as `None` use the box color, as do the
+    /// dividers and column separators inside. The left and right sides include the corners,
+    /// so a colored side reads as a full-height stripe.
+    Sides {
+        /// The top edge
+        top: Option<String>,
+        /// The right edge, corners included
+        right: Option<String>,
+        /// The bottom edge
+        bottom: Option<String>,
+        /// The left edge, corners included
+        left: Option<String>,
+    },
+}
+
+// A `BorderPaint` with its colors parsed, so rendering never re-parses them
+#[derive(Debug, Default, Clone, PartialEq)]
+pub(crate) enum Paint {
+    #[default]
+    Solid,
+    Gradient(GradientDirection, Vec<Color>),
+    // top, right, bottom, left
+    Sides([Option<Color>; 4]),
+}
+
+impl Paint {
+    pub(crate) fn resolve(paint: &BorderPaint) -> Paint {
+        let parse = |hex: &Option<String>| hex.as_deref().map(SegColor::parse_hexcolor);
+        match paint {
+            BorderPaint::Solid => Paint::Solid,
+            BorderPaint::Gradient { direction, stops } => Paint::Gradient(
+                *direction,
+                stops.iter().map(|s| SegColor::parse_hexcolor(s)).collect(),
+            ),
+            BorderPaint::Sides {
+                top,
+                right,
+                bottom,
+                left,
+            } => Paint::Sides([parse(top), parse(right), parse(bottom), parse(left)]),
+        }
+    }
+
+    // Back to the public form, colors as hex strings
+    #[cfg(feature = "serde")]
+    pub(crate) fn to_border_paint(&self) -> BorderPaint {
+        match self {
+            Paint::Solid => BorderPaint::Solid,
+            Paint::Gradient(direction, stops) => BorderPaint::Gradient {
+                direction: *direction,
+                stops: stops.iter().map(|c| hex(*c)).collect(),
+            },
+            Paint::Sides(sides) => {
+                let [top, right, bottom, left] = sides.map(|side| side.map(hex));
+                BorderPaint::Sides {
+                    top,
+                    right,
+                    bottom,
+                    left,
+                }
+            }
+        }
+    }
+
+    // The paint for a box whose height isn't known yet: gradients along the height run
+    // across it instead
+    pub(crate) fn streamed(&self) -> Paint {
+        match self {
+            Paint::Gradient(GradientDirection::Vertical | GradientDirection::Perimeter, stops) => {
+                Paint::Gradient(GradientDirection::Horizontal, stops.clone())
+            }
+            paint => paint.clone(),
+        }
+    }
+
+    // The color of the border glyph at column `x`, row `y` of a `width` x `height` box
+    pub(crate) fn color_at(
+        &self,
+        base: Color,
+        (x, y): (usize, usize),
+        (width, height): (usize, usize),
+    ) -> Color {
+        let (right, bottom) = (width.saturating_sub(1), height.saturating_sub(1));
+        match self {
+            Paint::Solid => base,
+            Paint::Gradient(_, stops) if stops.is_empty() => base,
+            Paint::Gradient(direction, stops) => match direction {
+                GradientDirection::Horizontal => interpolate(stops, ratio(x, right)),
+                GradientDirection::Vertical => interpolate(stops, ratio(y, bottom)),
+                GradientDirection::Perimeter => {
+                    // close the loop so the end meets the start at the top-left corner
+                    let mut ring = stops.clone();
+                    ring.push(stops[0]);
+                    let perimeter = 2 * (right + bottom);
+                    interpolate(&ring, ratio(perimeter_pos(x, y, right, bottom), perimeter))
+                }
+            },
+            Paint::Sides([top, right_col, bottom_col, left]) => {
+                let side = if x == 0 {
+                    left
+                } else if x == right {
+                    right_col
+                } else if y == 0 {
+                    top
+                } else if y == bottom {
+                    bottom_col
+                } else {
+                    &None
+                };
+                side.unwrap_or(base)
+            }
+        }
+    }
+}
+
+// Distance clockwise from the top-left corner to the nearest point on the frame
+fn perimeter_pos(x: usize, y: usize, right: usize, bottom: usize) -> usize {
+    // distances to the top, right, bottom and left edges
+    let distances = [y, right.saturating_sub(x), bottom.saturating_sub(y), x];
+    match (0..4).min_by_key(|&i| distances[i]).unwrap_or(0) {
+        0 => x,
+        1 => right + y,
+        2 => right + bottom + right.saturating_sub(x),
+        _ => 2 * right + bottom + bottom.saturating_sub(y),
+    }
+}
+
+fn ratio(pos: usize, len: usize) -> f64 {
+    match len {
+        0 => 0.0,
+        len => pos.min(len) as f64 / len as f64,
+    }
+}
+
+fn interpolate(stops: &[Color], t: f64) -> Color {
+    if stops.len() == 1 {
+        return stops[0];
+    }
+    let scaled = t * (stops.len() - 1) as f64;
+    let i = (scaled.floor() as usize).min(stops.len() - 2);
+    let local = scaled - i as f64;
+    let ((r1, g1, b1), (r2, g2, b2)) = (rgb(stops[i]), rgb(stops[i + 1]));
+    let mix = |a: u8, b: u8| (a as f64 + (b as f64 - a as f64) * local).round() as u8;
+    Color::TrueColor {
+        r: mix(r1, r2),
+        g: mix(g1, g2),
+        b: mix(b1, b2),
+    }
+}
+
+// `#rrggbb`, the form colors are given in
+#[cfg(any(feature = "html", feature = "svg", feature = "serde"))]
+pub(crate) fn hex(color: Color) -> String {
+    let (r, g, b) = rgb(color);
+    format!("#{:02x}{:02x}{:02x}", r, g, b)
+}
+
+// Parsed colors are always true-color, except the white fallback for invalid hex strings
+pub(crate) fn rgb(color: Color) -> (u8, u8, u8) {
+    match color {
+        Color::TrueColor { r, g, b } => (r, g, b),
+        _ => (255, 255, 255),
+    }
+}
+
+/// The glyphs a [`Shadow`] is drawn with.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum ShadowStyle {
+    /// Light shade, `░`
+    #[default]
+    Light,
+    /// Medium shade, `▒`
+    Medium,
+    /// Blank cells with the shadow color as their background
+    Dim,
+}
+
+/// A drop shadow cast to the right of and below a box.
+///
+/// The shadow is the box's outline moved `offset_x` columns right and `offset_y` rows
+/// down, drawn behind the box. It takes up room: boxes that size to the terminal shrink
+/// by `offset_x` columns to fit it, and centered or right-aligned boxes are positioned by
+/// their full footprint, shadow included.
+///
+/// # Examples
+///
+/// ```
+/// use boxy_cli::prelude::*;
+///
+/// let mut alert = Boxy::new(BoxType::Double, "#ff5555");
+/// alert.set_shadow(Shadow::new(ShadowStyle::Medium).offset(2, 1).color("#333333"));
+/// alert.add_text_sgmt("Disk almost full", "#ffffff", BoxAlign::Center);
+/// alert.display();
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(deny_unknown_fields, default))]
+pub struct Shadow {
+    /// The glyphs the shadow is drawn with
+    pub style: ShadowStyle,
+    /// Columns the shadow extends past the right edge
+    pub offset_x: usize,
+    /// Rows the shadow extends below the bottom edge
+    pub offset_y: usize,
+    /// Hex color of the shade glyphs, or of the background for [`ShadowStyle::Dim`]
+    pub color: String,
+}
+
+impl Default for Shadow {
+    fn default() -> Self {
+        Self {
+            style: ShadowStyle::Light,
+            offset_x: 2,
+            offset_y: 1,
+            color: "#555555".to_string(),
+        }
+    }
+}
+
+impl Shadow {
+    /// Creates a shadow in the given style, offset two columns right and one row down
+    /// (about square in most terminal fonts), in dark grey.
+    pub fn new(style: ShadowStyle) -> Self {
+        Self {
+            style,
+            ..Self::default()
+        }
+    }
+
+    /// Sets how many columns right and rows down the shadow is cast.
+    pub fn offset(mut self, x: usize, y: usize) -> Self {
+        self.offset_x = x;
+        self.offset_y = y;
+        self
+    }
+
+    /// Sets the shadow color from a hex code (e.g. `"#333333"`).
+    pub fn color(mut self, color: &str) -> Self {
+        self.color = color.to_string();
+        self
+    }
+}
+
+// A `Shadow` with its color parsed
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct ShadowPaint {
+    pub(crate) style: ShadowStyle,
+    pub(crate) offset: (usize, usize),
+    pub(crate) color: Color,
+}
+
+impl ShadowPaint {
+    pub(crate) fn resolve(shadow: &Shadow) -> ShadowPaint {
+        ShadowPaint {
+            style: shadow.style,
+            offset: (shadow.offset_x, shadow.offset_y),
+            color: SegColor::parse_hexcolor(&shadow.color),
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    pub(crate) fn to_shadow(self) -> Shadow {
+        Shadow {
+            style: self.style,
+            offset_x: self.offset.0,
+            offset_y: self.offset.1,
+            color: hex(self.color),
+        }
+    }
+
+    fn cells(&self, count: usize) -> Run {
+        let (glyph, ink) = match self.style {
+            ShadowStyle::Light => ("░", Ink::Fg(self.color)),
+            ShadowStyle::Medium => ("▒", Ink::Fg(self.color)),
+            ShadowStyle::Dim => (" ", Ink::Bg(self.color)),
+        };
+        Run {
+            text: glyph.repeat(count),
+            ink,
+        }
+    }
+}
+
+// A laid-out box: its lines, shadow included, and the size of the frame itself
+#[derive(Debug, Clone, Default, PartialEq)]
+pub(crate) struct Layout {
+    pub(crate) lines: Vec<Line>,
+    pub(crate) size: (usize, usize),
+}
+
+// One run of a laid-out line
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Span {
+    // Blanks: margins, alignment and padding
+    Space(usize),
+    // Border glyphs, one column each, colored by the paint
+    Border(String),
+    // Content in its own color
+    Text(String, Color),
+    // Drop shadow cells
+    Shadow(usize, ShadowPaint),
+}
+
+// One laid-out line of a box, before any color is applied
+#[derive(Debug, Clone, Default, PartialEq)]
+pub(crate) struct Line {
+    pub(crate) spans: Vec<Span>,
+}
+
+impl Line {
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+
+    pub(crate) fn space(&mut self, count: usize) {
+        if count == 0 {
+            return;
+        }
+        match self.spans.last_mut() {
+            Some(Span::Space(n)) => *n += count,
+            _ => self.spans.push(Span::Space(count)),
+        }
+    }
+
+    pub(crate) fn border(&mut self, glyph: char) {
+        match self.spans.last_mut() {
+            Some(Span::Border(glyphs)) => glyphs.push(glyph),
+            _ => self.spans.push(Span::Border(glyph.to_string())),
+        }
+    }
+
+    pub(crate) fn borders(&mut self, glyphs: impl IntoIterator<Item = char>) {
+        for glyph in glyphs {
+            self.border(glyph);
+        }
+    }
+
+    pub(crate) fn text(&mut self, text: &str, color: Color) {
+        if !text.is_empty() {
+            self.spans.push(Span::Text(text.to_string(), color));
+        }
+    }
+
+    pub(crate) fn shadow(&mut self, count: usize, shadow: ShadowPaint) {
+        if count > 0 {
+            self.spans.push(Span::Shadow(count, shadow));
+        }
+    }
+
+    // `glyph` right-aligned in `width` columns, matching `{:>width$}` formatting
+    pub(crate) fn edge(&mut self, width: usize, glyph: char) {
+        self.space(width.saturating_sub(1));
+        self.border(glyph);
+    }
+
+    // Applies the paint and color escapes; `size` is the box's width and height
+    pub(crate) fn paint(
+        &self,
+        paint: &Paint,
+        base: Color,
+        mode: ColorMode,
+        y: usize,
+        size: (usize, usize),
+    ) -> String {
+        self.runs(paint, base, y, size)
+            .iter()
+            .map(|run| mode.apply(run))
+            .collect()
+    }
+
+    // Splits the line into runs of one color each, picking border colors from the paint
+    pub(crate) fn runs(
+        &self,
+        paint: &Paint,
+        base: Color,
+        y: usize,
+        size: (usize, usize),
+    ) -> Vec<Run> {
+        let mut out = Vec::new();
+        let mut col = 0;
+        let mut origin = None;
+        for span in &self.spans {
+            match span {
+                Span::Space(n) => {
+                    out.push(Run {
+                        text: " ".repeat(*n),
+                        ink: Ink::Plain,
+                    });
+                    col += n;
+                }
+                Span::Text(text, color) => {
+                    out.push(Run {
+                        text: text.clone(),
+                        ink: Ink::Fg(*color),
+                    });
+                    col += UnicodeWidthStr::width(text.as_str());
+                }
+                Span::Shadow(n, shadow) => {
+                    out.push(shadow.cells(*n));
+                    col += n;
+                }
+                Span::Border(glyphs) => {
+                    let origin = *origin.get_or_insert(col);
+                    let first = out.len();
+                    // group neighbouring glyphs of the same color into one run
+                    for glyph in glyphs.chars() {
+                        let ink = Ink::Fg(paint.color_at(base, (col - origin, y), size));
+                        match out[first..].last_mut() {
+                            Some(run) if run.ink == ink => run.text.push(glyph),
+                            _ => out.push(Run {
+                                text: glyph.to_string(),
+                                ink,
+                            }),
+                        }
+                        col += 1;
+                    }
+                }
+            }
+        }
+        out
+    }
+}
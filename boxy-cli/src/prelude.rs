@@ -14,4 +14,20 @@
 
 pub use crate::boxer::*;
 pub use crate::boxy;
-pub use crate::constructs::{BoxAlign, BoxPad, BoxType};
+pub use crate::constructs::{
+    AsciiFallback, BoxAlign, BoxPad, BoxType, Divider, NonTtyMode, SegType,
+};
+pub use crate::diagnose::LayoutWarning;
+pub use crate::error::BoxyError;
+pub use crate::live::LiveBox;
+#[cfg(feature = "markdown")]
+pub use crate::markdown::{Emphasis, MarkdownOptions};
+pub use crate::paint::{BorderPaint, ColorMode, GradientDirection, Shadow, ShadowStyle};
+#[cfg(feature = "serde")]
+pub use crate::spec::{BoxySpec, SegmentColors, SegmentSpec, SpecError};
+pub use crate::stream::BoxWriter;
+#[cfg(feature = "svg")]
+pub use crate::svg::SvgOptions;
+pub use crate::templates::{BorderSet, BorderSetError};
+#[cfg(feature = "config")]
+pub use crate::theme::{Theme, ThemeError, ThemeSet};
@@ -0,0 +1,347 @@
+//! Serializable box specifications.
+//!
+//! Available with the `serde` cargo feature. A [`BoxySpec`] describes everything about a
+//! box except the terminal it ends up on: border, colors, padding, segments and their
+//! text. One process can build a box, send its spec as JSON (or any other serde format),
+//! and another can render it at its own terminal width.
+//!
+//! [`Boxy`] itself implements `Serialize` and `Deserialize` through its spec, and the
+//! building blocks ([`BoxType`], [`BoxAlign`], [`BoxPad`], [`SegType`], [`Divider`],
+//! [`BorderPaint`], [`Shadow`]...) derive them too. Colors are `#rrggbb` strings, enum
+//! values are `snake_case` names, and unknown keys are rejected.
+//!
+//! ```
+//! use boxy_cli::prelude::*;
+//!
+//! // the producer
+//! let mut b = Boxy::new(BoxType::Rounded, "#00ffff");
+//! b.add_text_sgmt("Backup finished", "#32cd32", BoxAlign::Center);
+//! let json = serde_json::to_string(&b).unwrap();
+//!
+//! // the client
+//! let b: Boxy = serde_json::from_str(&json).unwrap();
+//! b.display();
+//! ```
+//!
+//! A hand-written spec only needs the keys that differ from the defaults:
+//!
+//! ```
+//! use boxy_cli::prelude::*;
+//!
+//! let json = r##"{
+//!     "type": "double",
+//!     "color": "#ff5555",
+//!     "segments": [
+//!         {"content": {"single": ["Disk almost full"]}, "align": "center"},
+//!         {"content": {"columnar": [["/", "/home"], ["91%", "97%"]]}, "ratios": [3, 1]}
+//!     ]
+//! }"##;
+//! let spec: BoxySpec = serde_json::from_str(json).unwrap();
+//! let b = Boxy::from_spec(&spec).unwrap();
+//! assert_eq!(b.render_plain(30).len(), 6);
+//! ```
+
+use std::fmt;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::boxer::Boxy;
+use crate::constructs::{AsciiFallback, BoxAlign, BoxPad, BoxType, Divider, NonTtyMode, SegType};
+use crate::error::{
+    BoxyError, check_color, check_divider, check_paint, check_ratios, check_type, check_width,
+};
+use crate::paint::{BorderPaint, ColorMode, Shadow};
+
+/// Everything needed to rebuild a [`Boxy`], in a serializable form.
+///
+/// Get one from [`Boxy::to_spec`], or deserialize it; turn it back into a box with
+/// [`Boxy::from_spec`]. Every field has a default, so hand-written specs can leave out
+/// anything they don't need.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields, default)]
+pub struct BoxySpec {
+    /// The border style
+    #[serde(rename = "type")]
+    pub box_type: BoxType,
+    /// Hex color of the border
+    pub color: String,
+    /// How the border is colored; [`BorderPaint::Solid`] uses `color`
+    pub border_paint: BorderPaint,
+    /// The drop shadow, if any
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shadow: Option<Shadow>,
+    /// A separate style for dividers and column separators, if any
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub divider_style: Option<BoxType>,
+    /// Alignment of the box in the terminal
+    pub align: BoxAlign,
+    /// Padding between the border and the text
+    pub internal_padding: BoxPad,
+    /// Padding between the terminal edges and the border
+    pub external_padding: BoxPad,
+    /// Fixed width, or `None` to size to the terminal
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub width: Option<usize>,
+    /// Fixed height, or `None` to fit the content
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub height: Option<usize>,
+    /// When to draw the border in ASCII
+    pub ascii_fallback: AsciiFallback,
+    /// Whether output is colored
+    pub color_mode: ColorMode,
+    /// What is printed when the terminal size is unknown
+    pub non_tty_mode: NonTtyMode,
+    /// The segments, top to bottom
+    pub segments: Vec<SegmentSpec>,
+}
+
+impl Default for BoxySpec {
+    fn default() -> Self {
+        Self {
+            box_type: BoxType::default(),
+            color: "#ffffff".to_string(),
+            border_paint: BorderPaint::default(),
+            shadow: None,
+            divider_style: None,
+            align: BoxAlign::default(),
+            internal_padding: BoxPad::default(),
+            external_padding: BoxPad::default(),
+            width: None,
+            height: None,
+            ascii_fallback: AsciiFallback::default(),
+            color_mode: ColorMode::default(),
+            non_tty_mode: NonTtyMode::default(),
+            segments: Vec::new(),
+        }
+    }
+}
+
+/// One segment of a [`BoxySpec`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SegmentSpec {
+    /// The text, one string per line (and per column for [`SegType::Columnar`])
+    pub content: SegType,
+    /// Hex colors in the same shape as `content`, one per line; `None` draws every line
+    /// in white
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub colors: Option<SegmentColors>,
+    /// Alignment of the text within the box
+    #[serde(default)]
+    pub align: BoxAlign,
+    /// Column width ratios for a columnar segment; `None` splits evenly
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ratios: Option<Vec<usize>>,
+    /// What separates this segment from the next one
+    #[serde(default)]
+    pub divider: Divider,
+}
+
+/// The text colors of a [`SegmentSpec`]: hex colors in the same shape as its content.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SegmentColors {
+    /// One color per line of a [`SegType::Single`] segment
+    Single(Vec<String>),
+    /// One list of colors per column of a [`SegType::Columnar`] segment, one color per line
+    Columnar(Vec<Vec<String>>),
+}
+
+/// An error turning a [`BoxySpec`] into a [`Boxy`].
+///
+/// Deserializing already rejects unknown keys and malformed values; this covers what a
+/// well-formed spec can still get wrong, like invalid colors or colors that don't match
+/// their segment's lines.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SpecError {
+    key: String,
+    message: String,
+}
+
+impl SpecError {
+    fn new(key: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            key: key.into(),
+            message: message.into(),
+        }
+    }
+
+    /// The path of the offending value, e.g. `segments[1].colors`.
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+}
+
+impl fmt::Display for SpecError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.key, self.message)
+    }
+}
+
+impl std::error::Error for SpecError {}
+
+impl Serialize for Boxy {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.to_spec().serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Boxy {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let spec = BoxySpec::deserialize(deserializer)?;
+        Boxy::from_spec(&spec).map_err(serde::de::Error::custom)
+    }
+}
+
+impl Boxy {
+    /// Rebuilds a box from its spec.
+    ///
+    /// Available with the `serde` cargo feature.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`SpecError`] naming the offending key if a color isn't a valid hex
+    /// code, a custom border glyph isn't one column wide, the fixed width is too narrow,
+    /// a segment's colors don't have the shape of its content, or column ratios are given
+    /// for a single segment or don't match the column count.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use boxy_cli::prelude::*;
+    ///
+    /// let spec: BoxySpec = serde_json::from_str(r##"{"color": "#zzz"}"##).unwrap();
+    /// let err = Boxy::from_spec(&spec).unwrap_err();
+    /// assert_eq!(err.to_string(), "color: invalid hex color \"#zzz\"");
+    /// ```
+    pub fn from_spec(spec: &BoxySpec) -> Result<Boxy, SpecError> {
+        check_type(&spec.box_type).map_err(at("type"))?;
+        if let Some(style) = &spec.divider_style {
+            check_type(style).map_err(at("divider_style"))?;
+        }
+        check_color(&spec.color).map_err(at("color"))?;
+        check_paint(&spec.border_paint).map_err(at("border_paint"))?;
+        if let Some(shadow) = &spec.shadow {
+            check_color(&shadow.color).map_err(at("shadow.color"))?;
+        }
+
+        let mut b = Boxy::new(spec.box_type.clone(), &spec.color);
+        b.set_border_paint(spec.border_paint.clone());
+        if let Some(shadow) = &spec.shadow {
+            b.set_shadow(shadow.clone());
+        }
+        if let Some(style) = &spec.divider_style {
+            b.set_divider_style(style.clone());
+        }
+        b.set_align(spec.align);
+        b.set_padding(spec.external_padding, spec.internal_padding);
+        if let Some(width) = spec.width {
+            check_width(width, &spec.internal_padding).map_err(at("width"))?;
+            b.set_width(width);
+        }
+        if let Some(height) = spec.height {
+            b.set_height(height);
+        }
+        b.set_ascii_fallback(spec.ascii_fallback);
+        b.set_color_mode(spec.color_mode);
+        b.set_non_tty_mode(spec.non_tty_mode);
+
+        for (i, seg) in spec.segments.iter().enumerate() {
+            let key = format!("segments[{}]", i);
+            let colors = segment_colors(seg, &key)?;
+            match (&seg.content, colors) {
+                (SegType::Single(lines), SegmentColors::Single(colors)) => {
+                    let Some((first, rest)) = lines.split_first() else {
+                        return Err(SpecError::new(
+                            format!("{}.content", key),
+                            "a single segment needs at least one line",
+                        ));
+                    };
+                    b.add_text_sgmt(first, &colors[0], seg.align);
+                    for (line, color) in rest.iter().zip(&colors[1..]) {
+                        b.add_text_line(line, color);
+                    }
+                }
+                (SegType::Columnar(cols), SegmentColors::Columnar(colors)) => {
+                    if cols.is_empty() {
+                        return Err(SpecError::new(
+                            format!("{}.content", key),
+                            "a columnar segment needs at least one column",
+                        ));
+                    }
+                    b.add_col_text_sgmt(seg.align, cols.len());
+                    for (c, (col, colors)) in cols.iter().zip(&colors).enumerate() {
+                        for (line, color) in col.iter().zip(colors) {
+                            b.add_col_text_line_indx(line, color, &i, &c);
+                        }
+                    }
+                }
+                _ => unreachable!("segment_colors returns the shape of the content"),
+            }
+            if let Some(ratios) = &seg.ratios {
+                check_ratios(b.seg_data(), i, ratios).map_err(at(format!("{}.ratios", key)))?;
+                b.set_segment_ratios(i, ratios.clone());
+            }
+            check_divider(&seg.divider).map_err(at(format!("{}.divider", key)))?;
+            b.set_divider(i, seg.divider.clone());
+        }
+        Ok(b)
+    }
+}
+
+// The segment's colors, checked against its content, or white for every line
+fn segment_colors(seg: &SegmentSpec, key: &str) -> Result<SegmentColors, SpecError> {
+    let white = || "#ffffff".to_string();
+    let key = format!("{}.colors", key);
+    match (&seg.content, &seg.colors) {
+        (SegType::Single(lines), None) => Ok(SegmentColors::Single(vec![white(); lines.len()])),
+        (SegType::Columnar(cols), None) => Ok(SegmentColors::Columnar(
+            cols.iter().map(|col| vec![white(); col.len()]).collect(),
+        )),
+        (SegType::Single(lines), Some(SegmentColors::Single(colors))) => {
+            if colors.len() != lines.len() {
+                return Err(SpecError::new(
+                    key,
+                    format!("expected {} colors, found {}", lines.len(), colors.len()),
+                ));
+            }
+            for (n, color) in colors.iter().enumerate() {
+                check_color(color).map_err(at(format!("{}[{}]", key, n)))?;
+            }
+            Ok(SegmentColors::Single(colors.clone()))
+        }
+        (SegType::Columnar(cols), Some(SegmentColors::Columnar(colors))) => {
+            if colors.len() != cols.len() {
+                return Err(SpecError::new(
+                    key,
+                    format!("expected {} columns, found {}", cols.len(), colors.len()),
+                ));
+            }
+            for (c, (col, colors)) in cols.iter().zip(colors).enumerate() {
+                if colors.len() != col.len() {
+                    return Err(SpecError::new(
+                        format!("{}[{}]", key, c),
+                        format!("expected {} colors, found {}", col.len(), colors.len()),
+                    ));
+                }
+                for (n, color) in colors.iter().enumerate() {
+                    check_color(color).map_err(at(format!("{}[{}][{}]", key, c, n)))?;
+                }
+            }
+            Ok(SegmentColors::Columnar(colors.clone()))
+        }
+        (SegType::Single(_), Some(_)) => Err(SpecError::new(
+            key,
+            "expected single colors for a single segment",
+        )),
+        (SegType::Columnar(_), Some(_)) => Err(SpecError::new(
+            key,
+            "expected columnar colors for a columnar segment",
+        )),
+    }
+}
+
+// Attaches the spec key to an error from the checks `Boxy` itself runs
+fn at(key: impl Into<String>) -> impl FnOnce(BoxyError) -> SpecError {
+    move |e| SpecError::new(key, e.to_string())
+}
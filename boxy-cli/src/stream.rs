@@ -0,0 +1,261 @@
+//! Boxes written line by line as their content arrives.
+//!
+//! A [`BoxWriter`] draws a box into any [`io::Write`] while it is being filled: the top
+//! border goes out when the writer is created, each complete line as soon as it is
+//! written, and the bottom border on [`finish`](BoxWriter::finish) or drop. That suits
+//! long-running tasks whose output should appear as it happens rather than all at once.
+//!
+//! ```
+//! use std::io::Write;
+//! use boxy_cli::prelude::*;
+//!
+//! let mut header = Boxy::new(BoxType::Rounded, "#00ffff");
+//! header.add_text_sgmt("Deploy", "#ffffff", BoxAlign::Center);
+//! header.set_width(20);
+//!
+//! let mut bw = BoxWriter::new(header, Vec::new(), 40)?;
+//! writeln!(bw, "step 1 ok")?;
+//! writeln!(bw, "step 2 ok")?;
+//! bw.new_segment(Divider::Rule)?;
+//! bw.set_color("#00ff00");
+//! writeln!(bw, "done")?;
+//!
+//! let out = String::from_utf8(bw.finish()?).unwrap();
+//! assert_eq!(out.lines().count(), 8);
+//! # Ok::<(), std::io::Error>(())
+//! ```
+
+use std::io::{self, Write};
+
+use colored::Color;
+
+use crate::boxer::{Boxy, Frame};
+use crate::constructs::{BoxAlign, Divider, SegColor};
+use crate::error::{BoxyError, check_color};
+use crate::paint::Line;
+
+/// Draws a box into a writer line by line, as its content arrives.
+///
+/// The [`Boxy`] the writer is created from sets the style: border type and paint, color
+/// mode, padding, width and alignment. Any segments it already has are drawn right away,
+/// above the streamed text, e.g. a title. Everything written afterwards goes into the box
+/// one line per `\n`: each line is wrapped to the box width and bordered as soon as it is
+/// complete. A line still missing its `\n` is held back until it is complete, a segment
+/// break or the end of the box.
+///
+/// Streamed lines follow each other directly, without the blank lines that separate the
+/// lines of one [`Boxy`] segment. The height of the box isn't known while it is written,
+/// so [`set_height`](Boxy::set_height) and shadows are ignored, and vertical and perimeter
+/// gradients are drawn horizontally.
+///
+/// The writer itself isn't buffered; wrap the target in an [`io::BufWriter`] if
+/// it writes straight to a file or socket.
+#[derive(Debug)]
+pub struct BoxWriter<W: Write> {
+    // `None` once the box is finished
+    out: Option<W>,
+    boxy: Boxy,
+    frame: Frame,
+    // rows written so far
+    y: usize,
+    // column boundaries of the segment above, which the next rule joins
+    above: Vec<usize>,
+    // drawn before the next line
+    divider: Option<Divider>,
+    color: Color,
+    align: BoxAlign,
+    partial: Vec<u8>,
+    // set once closing starts, so a failed `finish` isn't retried on drop
+    closed: bool,
+}
+
+impl<W: Write> BoxWriter<W> {
+    /// Starts a box in `out`, writing its top border and any segments `boxy` already has.
+    ///
+    /// Streamed text is white and left-aligned until changed with
+    /// [`set_color`](Self::set_color) and [`set_align`](Self::set_align).
+    ///
+    /// # Arguments
+    ///
+    /// * `boxy` - The box to continue: its style, and segments to draw above the stream
+    /// * `out` - The writer to draw into
+    /// * `term_width` - The total column width to render into, as for
+    ///   [`Boxy::render`]
+    ///
+    /// # Errors
+    ///
+    /// Returns the error from writing the top of the box.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io::Write;
+    /// use boxy_cli::prelude::*;
+    ///
+    /// let mut bw = BoxWriter::new(Boxy::new(BoxType::Single, "#00ffff"), std::io::stdout(), 60)?;
+    /// writeln!(bw, "compiling...")?;
+    /// bw.finish()?;
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    pub fn new(boxy: Boxy, out: W, term_width: usize) -> io::Result<Self> {
+        let frame = boxy.frame(term_width);
+        let (lines, above, divider) = boxy.stream_open(&frame);
+        let mut writer = BoxWriter {
+            out: Some(out),
+            boxy,
+            frame,
+            y: 0,
+            above,
+            divider,
+            color: SegColor::parse_hexcolor("#ffffff"),
+            align: BoxAlign::Left,
+            partial: Vec::new(),
+            closed: false,
+        };
+        for line in &lines {
+            writer.emit(line, false)?;
+        }
+        Ok(writer)
+    }
+
+    /// Sets the hex color of the lines written from now on.
+    ///
+    /// Falls back to white with a stderr warning on invalid input; see
+    /// [`try_set_color`](Self::try_set_color).
+    pub fn set_color(&mut self, color: &str) {
+        self.color = SegColor::parse_hexcolor(color);
+    }
+
+    /// Sets the hex color of the lines written from now on, like
+    /// [`set_color`](Self::set_color).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BoxyError::InvalidColor`] if `color` isn't a hex color code, and keeps the
+    /// current color.
+    pub fn try_set_color(&mut self, color: &str) -> Result<(), BoxyError> {
+        check_color(color)?;
+        self.set_color(color);
+        Ok(())
+    }
+
+    /// Sets the alignment of the lines written from now on.
+    pub fn set_align(&mut self, align: BoxAlign) {
+        self.align = align;
+    }
+
+    /// Ends the current segment; the next line starts a new one below `divider`.
+    ///
+    /// A line still missing its `\n` ends the segment. The divider is drawn with the next
+    /// line, so a break just before [`finish`](Self::finish) adds nothing, and of several
+    /// breaks in a row only the last one counts.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error from writing the unfinished line, if any.
+    pub fn new_segment(&mut self, divider: Divider) -> io::Result<()> {
+        self.flush_partial()?;
+        self.divider = Some(divider);
+        Ok(())
+    }
+
+    /// Writes any unfinished line and the bottom border, flushes, and returns the writer.
+    ///
+    /// Dropping the writer finishes the box too, but ignores errors. If finishing fails,
+    /// the box is left as it is; dropping doesn't try again.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error from writing or flushing.
+    pub fn finish(mut self) -> io::Result<W> {
+        self.close()?;
+        Ok(self.out.take().expect("the box is only finished once"))
+    }
+
+    /// The underlying writer.
+    pub fn get_ref(&self) -> &W {
+        self.out.as_ref().expect("the box is only finished once")
+    }
+
+    fn close(&mut self) -> io::Result<()> {
+        self.closed = true;
+        self.flush_partial()?;
+        let bottom = self.boxy.stream_close(&self.frame, &self.above);
+        self.emit(&bottom, true)?;
+        self.out_mut().flush()
+    }
+
+    // Draws the line: the pending divider first, then the text, wrapped
+    fn line(&mut self, text: &str) -> io::Result<()> {
+        if let Some(divider) = self.divider.take() {
+            if let Some(line) = self.boxy.stream_divider(&self.frame, &divider, &self.above) {
+                self.emit(&line, false)?;
+            }
+            self.above.clear();
+        }
+        for line in self
+            .boxy
+            .stream_text(&self.frame, text, self.color, &self.align)
+        {
+            self.emit(&line, false)?;
+        }
+        Ok(())
+    }
+
+    fn flush_partial(&mut self) -> io::Result<()> {
+        if self.partial.is_empty() {
+            return Ok(());
+        }
+        let partial = std::mem::take(&mut self.partial);
+        self.line(&String::from_utf8_lossy(&partial))
+    }
+
+    fn emit(&mut self, line: &Line, last: bool) -> io::Result<()> {
+        let painted = self.boxy.stream_paint(line, self.y, last, &self.frame);
+        self.y += 1;
+        writeln!(self.out_mut(), "{}", painted)
+    }
+
+    fn out_mut(&mut self) -> &mut W {
+        self.out.as_mut().expect("the box is only finished once")
+    }
+}
+
+impl<W: Write> Write for BoxWriter<W> {
+    /// Takes in text, drawing every line as soon as its `\n` arrives.
+    ///
+    /// If drawing a line fails, the bytes of the lines drawn before it are reported as
+    /// written, and the error is returned only when there are none.
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let mut consumed = 0;
+        while let Some(end) = buf[consumed..].iter().position(|&b| b == b'\n') {
+            let held = self.partial.len();
+            self.partial
+                .extend_from_slice(&buf[consumed..consumed + end]);
+            let mut line = std::mem::take(&mut self.partial);
+            let text = String::from_utf8_lossy(&line).into_owned();
+            if let Err(e) = self.line(text.strip_suffix('\r').unwrap_or(&text)) {
+                // hold back what came before this call again; the rest is not consumed
+                line.truncate(held);
+                self.partial = line;
+                return if consumed > 0 { Ok(consumed) } else { Err(e) };
+            }
+            consumed += end + 1;
+        }
+        self.partial.extend_from_slice(&buf[consumed..]);
+        Ok(buf.len())
+    }
+
+    /// Flushes the underlying writer. An unfinished line stays held back.
+    fn flush(&mut self) -> io::Result<()> {
+        self.out_mut().flush()
+    }
+}
+
+impl<W: Write> Drop for BoxWriter<W> {
+    fn drop(&mut self) {
+        if !self.closed {
+            let _ = self.close();
+        }
+    }
+}
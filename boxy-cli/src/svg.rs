@@ -0,0 +1,179 @@
+//! SVG export, for images of boxes in READMEs and docs.
+//!
+//! Available with the `svg` cargo feature. [`Boxy::render_svg`] lays out the same
+//! character grid as [`Boxy::render`]: every run of same-colored characters becomes a
+//! `<text>` element pinned to its cells, so the picture matches the terminal output
+//! whatever font the viewer falls back to. Generating images from code keeps doc assets
+//! in sync with the library, e.g. from a test or a build script.
+//!
+//! ```
+//! use boxy_cli::prelude::*;
+//!
+//! let mut b = Boxy::new(BoxType::Rounded, "#00ffff");
+//! b.add_text_sgmt("boxy-cli", "#ffffff", BoxAlign::Center);
+//!
+//! let svg = b.render_svg(30, &SvgOptions::default().background(Some("#000000")));
+//! assert!(svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\""));
+//! # let path = std::env::temp_dir().join("boxy-doc-example.svg");
+//! std::fs::write(&path, svg)?;
+//! # std::fs::remove_file(&path)?;
+//! # Ok::<(), std::io::Error>(())
+//! ```
+
+use std::fmt::Write;
+
+use unicode_width::UnicodeWidthStr;
+
+use crate::boxer::Boxy;
+use crate::export::escape;
+use crate::paint::{Ink, hex};
+
+/// Font, cell size and colors for [`Boxy::render_svg`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct SvgOptions {
+    /// CSS font family list; it should end in a monospace font with box-drawing glyphs
+    pub font_family: String,
+    /// Font size in pixels
+    pub font_size: f64,
+    /// Width of one terminal column in pixels
+    pub cell_width: f64,
+    /// Height of one terminal row in pixels
+    pub cell_height: f64,
+    /// Hex color filling the whole image, or `None` for a transparent background
+    pub background: Option<String>,
+}
+
+impl Default for SvgOptions {
+    fn default() -> Self {
+        Self {
+            font_family: "'DejaVu Sans Mono', Menlo, Consolas, monospace".to_string(),
+            font_size: 14.0,
+            cell_width: 8.4,
+            cell_height: 17.0,
+            background: Some("#1e1e1e".to_string()),
+        }
+    }
+}
+
+impl SvgOptions {
+    /// Sets the CSS font family and the font size in pixels.
+    pub fn font(mut self, family: &str, size: f64) -> Self {
+        self.font_family = family.to_string();
+        self.font_size = size;
+        self
+    }
+
+    /// Sets the width and height of one character cell in pixels.
+    pub fn cell_size(mut self, width: f64, height: f64) -> Self {
+        self.cell_width = width;
+        self.cell_height = height;
+        self
+    }
+
+    /// Sets the background hex color, or `None` for a transparent background.
+    pub fn background(mut self, color: Option<&str>) -> Self {
+        self.background = color.map(str::to_string);
+        self
+    }
+}
+
+impl Boxy {
+    /// Renders the text box as an SVG image.
+    ///
+    /// The image is one cell per terminal column and row of [`render`](Self::render) for
+    /// the same `term_width`, so margins and alignment carry over. Border and text colors
+    /// are always drawn, whatever the [`ColorMode`](crate::paint::ColorMode); shadows drawn
+    /// with [`ShadowStyle::Dim`](crate::paint::ShadowStyle::Dim) become filled cells.
+    ///
+    /// Available with the `svg` cargo feature.
+    ///
+    /// # Arguments
+    ///
+    /// * `term_width` - The total column width to render into, as for [`render`](Self::render)
+    /// * `options` - The font, cell size and background to draw with
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use boxy_cli::prelude::*;
+    ///
+    /// let mut b = Boxy::new(BoxType::Single, "#00ffff");
+    /// b.add_text_sgmt("Hi", "#ff0000", BoxAlign::Left);
+    /// b.set_width(10);
+    ///
+    /// let options = SvgOptions::default().cell_size(10.0, 20.0).background(None);
+    /// let svg = b.render_svg(10, &options);
+    /// assert!(svg.contains("width=\"100\" height=\"60\""));
+    /// assert!(svg.contains("fill=\"#ff0000\""));
+    /// ```
+    pub fn render_svg(&self, term_width: usize, options: &SvgOptions) -> String {
+        let lines = self.runs(term_width);
+        let columns = lines
+            .iter()
+            .map(|line| line.iter().map(|run| run.text.width()).sum::<usize>())
+            .max()
+            .unwrap_or(0);
+        let (cw, ch) = (options.cell_width, options.cell_height);
+        let (width, height) = (columns as f64 * cw, lines.len() as f64 * ch);
+
+        let mut out = String::new();
+        let _ = writeln!(
+            out,
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}\" height=\"{}\" viewBox=\"0 0 {} {}\" font-family=\"{}\" font-size=\"{}\" xml:space=\"preserve\">",
+            num(width),
+            num(height),
+            num(width),
+            num(height),
+            escape(&options.font_family),
+            num(options.font_size)
+        );
+        if let Some(background) = &options.background {
+            let _ = writeln!(
+                out,
+                "<rect width=\"100%\" height=\"100%\" fill=\"{}\"/>",
+                escape(background)
+            );
+        }
+        // centre the glyphs' x-height in their row
+        let baseline = (ch + options.font_size * 0.7) / 2.0;
+        for (row, line) in lines.iter().enumerate() {
+            let y = row as f64 * ch;
+            let mut col = 0;
+            for run in line {
+                let cells = run.text.width();
+                let x = col as f64 * cw;
+                let _ = match run.ink {
+                    Ink::Plain => Ok(()),
+                    Ink::Bg(color) => writeln!(
+                        out,
+                        "<rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" fill=\"{}\"/>",
+                        num(x),
+                        num(y),
+                        num(cells as f64 * cw),
+                        num(ch),
+                        hex(color)
+                    ),
+                    // pin the run to its cells, whatever the font's advance widths
+                    Ink::Fg(color) => writeln!(
+                        out,
+                        "<text x=\"{}\" y=\"{}\" fill=\"{}\" textLength=\"{}\" lengthAdjust=\"spacingAndGlyphs\">{}</text>",
+                        num(x),
+                        num(y + baseline),
+                        hex(color),
+                        num(cells as f64 * cw),
+                        escape(&run.text)
+                    ),
+                };
+                col += cells;
+            }
+        }
+        out.push_str("</svg>\n");
+        out
+    }
+}
+
+// A pixel value with at most two decimals and no trailing zeros
+fn num(value: f64) -> String {
+    let s = format!("{:.2}", value);
+    s.trim_end_matches('0').trim_end_matches('.').to_string()
+}
@@ -1,20 +1,431 @@
 //! The structs for the box border characters
-#[allow(dead_code)]
-pub(crate) struct BoxTemplates {
-    pub(crate) top_left: char,
-    pub(crate) top_right: char,
-    pub(crate) bottom_left: char,
-    pub(crate) bottom_right: char,
-    pub(crate) vertical: char,
-    pub(crate) horizontal: char,
-    pub(crate) left_t: char,
-    pub(crate) right_t: char,
-    pub(crate) upper_t: char,
-    pub(crate) lower_t: char,
-    pub(crate) cross: char,
+
+use std::fmt;
+
+use unicode_width::UnicodeWidthChar;
+
+/// The glyphs used to draw a box border: four corners, the two edges, the four T-junctions
+/// where dividers meet the frame, and the cross where dividers meet column separators.
+///
+/// Built-in styles are chosen through [`BoxType`](crate::BoxType); a `BorderSet` lets you
+/// draw with your own glyphs via [`BoxType::Custom`](crate::BoxType::Custom). Every glyph
+/// must occupy exactly one terminal column — see [`validate`](Self::validate).
+///
+/// # Bottom and right edges
+///
+/// `horizontal` and `vertical` draw all four edges unless `bottom` or `right` is set. Set
+/// them when the far edges need a different glyph, e.g. half blocks that hug the content
+/// from both sides: `▀` along the top and `▄` along the bottom.
+///
+/// # Examples
+///
+/// ```
+/// use boxy_cli::prelude::*;
+///
+/// // corners, edges, T-junctions and cross, in field order
+/// let mut blocks = BorderSet::from_glyphs("▛▜▙▟▌▀▌▐▀▄█").unwrap();
+/// blocks.bottom = Some('▄');
+/// blocks.right = Some('▐');
+///
+/// let mut b = Boxy::new(BoxType::Custom(blocks), "#ff8800");
+/// b.add_text_sgmt("Brand-styled box", "#ffffff", BoxAlign::Center);
+/// b.display();
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
+pub struct BorderSet {
+    /// Top-left corner
+    pub top_left: char,
+    /// Top-right corner
+    pub top_right: char,
+    /// Bottom-left corner
+    pub bottom_left: char,
+    /// Bottom-right corner
+    pub bottom_right: char,
+    /// Left edge, right edge unless `right` is set, and the separators between columns
+    pub vertical: char,
+    /// Top edge, bottom edge unless `bottom` is set, and the dividers between segments
+    pub horizontal: char,
+    /// Where a divider meets the left edge
+    pub left_t: char,
+    /// Where a divider meets the right edge
+    pub right_t: char,
+    /// Where a column separator meets the top edge or a divider from below
+    pub upper_t: char,
+    /// Where a column separator meets the bottom edge or a divider from above
+    pub lower_t: char,
+    /// Where a divider crosses a column separator
+    pub cross: char,
+    /// Bottom edge, if it differs from `horizontal`
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub bottom: Option<char>,
+    /// Right edge, if it differs from `vertical`
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub right: Option<char>,
+}
+
+impl BorderSet {
+    // Slot names, in field order; shared with theme files and error messages
+    pub(crate) const SLOTS: [&'static str; 11] = [
+        "top_left",
+        "top_right",
+        "bottom_left",
+        "bottom_right",
+        "vertical",
+        "horizontal",
+        "left_t",
+        "right_t",
+        "upper_t",
+        "lower_t",
+        "cross",
+    ];
+
+    // The optional far edges, which aren't part of `from_glyphs`
+    pub(crate) const EDGE_SLOTS: [&'static str; 2] = ["bottom", "right"];
+
+    /// Builds a border set from a string of exactly eleven glyphs, given in field order:
+    /// `top_left`, `top_right`, `bottom_left`, `bottom_right`, `vertical`, `horizontal`,
+    /// `left_t`, `right_t`, `upper_t`, `lower_t`, `cross`. `bottom` and `right` are left
+    /// unset, so `horizontal` and `vertical` draw all four edges.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BorderSetError::GlyphCount`] if the string doesn't hold exactly eleven
+    /// characters, or [`BorderSetError::Width`] if any glyph isn't one column wide.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use boxy_cli::prelude::*;
+    ///
+    /// let ascii = BorderSet::from_glyphs("++++|-+++++").unwrap();
+    /// assert_eq!(ascii.vertical, '|');
+    ///
+    /// assert!(BorderSet::from_glyphs("+-|").is_err());
+    /// ```
+    pub fn from_glyphs(glyphs: &str) -> Result<Self, BorderSetError> {
+        let chars: Vec<char> = glyphs.chars().collect();
+        let [
+            top_left,
+            top_right,
+            bottom_left,
+            bottom_right,
+            vertical,
+            horizontal,
+            left_t,
+            right_t,
+            upper_t,
+            lower_t,
+            cross,
+        ] = chars[..]
+        else {
+            return Err(BorderSetError::GlyphCount(chars.len()));
+        };
+        let set = BorderSet {
+            top_left,
+            top_right,
+            bottom_left,
+            bottom_right,
+            vertical,
+            horizontal,
+            left_t,
+            right_t,
+            upper_t,
+            lower_t,
+            cross,
+            bottom: None,
+            right: None,
+        };
+        set.validate()?;
+        Ok(set)
+    }
+
+    /// Returns the eleven glyphs in field order (the order [`from_glyphs`](Self::from_glyphs)
+    /// takes them in), without `bottom` and `right`.
+    pub fn glyphs(&self) -> [char; 11] {
+        [
+            self.top_left,
+            self.top_right,
+            self.bottom_left,
+            self.bottom_right,
+            self.vertical,
+            self.horizontal,
+            self.left_t,
+            self.right_t,
+            self.upper_t,
+            self.lower_t,
+            self.cross,
+        ]
+    }
+
+    /// Checks that every glyph occupies exactly one terminal column.
+    ///
+    /// Wide glyphs (most emoji, CJK), zero-width glyphs (combining marks) and control
+    /// characters would break the box geometry. A set that fails validation can still be
+    /// placed in [`BoxType::Custom`](crate::BoxType::Custom) by hand; when rendered, each
+    /// offending glyph is replaced by its [`BoxType::Single`](crate::BoxType::Single)
+    /// counterpart.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BorderSetError::Width`] for the first glyph that isn't one column wide.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use boxy_cli::prelude::*;
+    ///
+    /// let mut set = BorderSet::from_glyphs("┌┐└┘│─├┤┬┴┼").unwrap();
+    /// assert!(set.validate().is_ok());
+    ///
+    /// set.cross = '🦀'; // two columns wide
+    /// assert!(set.validate().is_err());
+    /// ```
+    pub fn validate(&self) -> Result<(), BorderSetError> {
+        for (slot, glyph) in Self::SLOTS.iter().zip(self.glyphs()) {
+            Self::check_glyph(slot, glyph)?;
+        }
+        for (slot, glyph) in Self::EDGE_SLOTS.iter().zip([self.bottom, self.right]) {
+            if let Some(glyph) = glyph {
+                Self::check_glyph(slot, glyph)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the closest pure-ASCII equivalent of this set.
+    ///
+    /// Glyphs that are already ASCII are kept. Corners, T-junctions and the cross become
+    /// `+`, and `vertical` and `right` become `|`. `horizontal` and `bottom` become `=` for
+    /// double and heavy lines, and `-` otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use boxy_cli::prelude::*;
+    ///
+    /// let double = BorderSet::from_glyphs("╔╗╚╝║═╠╣╦╩╬").unwrap();
+    /// let ascii: String = double.to_ascii().glyphs().iter().collect();
+    /// assert_eq!(ascii, "++++|=+++++");
+    /// ```
+    pub fn to_ascii(&self) -> BorderSet {
+        let mut set = *self;
+        for slot in Self::SLOTS {
+            let glyph = set.slot_mut(slot).expect("SLOTS only holds valid names");
+            *glyph = ascii_glyph(slot, *glyph);
+        }
+        set.bottom = self.bottom.map(|glyph| ascii_glyph("horizontal", glyph));
+        set.right = self.right.map(|glyph| ascii_glyph("vertical", glyph));
+        set
+    }
+
+    pub(crate) fn check_glyph(slot: &'static str, glyph: char) -> Result<(), BorderSetError> {
+        match UnicodeWidthChar::width(glyph) {
+            Some(1) => Ok(()),
+            width => Err(BorderSetError::Width {
+                slot,
+                glyph,
+                width: width.unwrap_or(0),
+            }),
+        }
+    }
+
+    // Sets the glyph named by `slot`, one of `SLOTS` or `EDGE_SLOTS`; false for other names
+    #[cfg(feature = "config")]
+    pub(crate) fn set_glyph(&mut self, slot: &str, glyph: char) -> bool {
+        match slot {
+            "bottom" => self.bottom = Some(glyph),
+            "right" => self.right = Some(glyph),
+            _ => match self.slot_mut(slot) {
+                Some(own) => *own = glyph,
+                None => return false,
+            },
+        }
+        true
+    }
+
+    pub(crate) fn slot_mut(&mut self, slot: &str) -> Option<&mut char> {
+        match slot {
+            "top_left" => Some(&mut self.top_left),
+            "top_right" => Some(&mut self.top_right),
+            "bottom_left" => Some(&mut self.bottom_left),
+            "bottom_right" => Some(&mut self.bottom_right),
+            "vertical" => Some(&mut self.vertical),
+            "horizontal" => Some(&mut self.horizontal),
+            "left_t" => Some(&mut self.left_t),
+            "right_t" => Some(&mut self.right_t),
+            "upper_t" => Some(&mut self.upper_t),
+            "lower_t" => Some(&mut self.lower_t),
+            "cross" => Some(&mut self.cross),
+            _ => None,
+        }
+    }
+
+    // Swaps every glyph that isn't one column wide for the single-line equivalent, so
+    // hand-built sets can never break the box geometry
+    pub(crate) fn sanitized(&self) -> BorderSet {
+        let mut set = *self;
+        for slot in Self::SLOTS {
+            let glyph = set.slot_mut(slot).expect("SLOTS only holds valid names");
+            if Self::check_glyph(slot, *glyph).is_err() {
+                let mut fallback = SINGLE_TEMPLATE;
+                *glyph = *fallback
+                    .slot_mut(slot)
+                    .expect("SLOTS only holds valid names");
+            }
+        }
+        // an unusable far edge is drawn like the near one
+        set.bottom = self
+            .bottom
+            .filter(|g| Self::check_glyph("bottom", *g).is_ok());
+        set.right = self
+            .right
+            .filter(|g| Self::check_glyph("right", *g).is_ok());
+        set
+    }
+
+    // The glyph drawn along the bottom edge
+    pub(crate) fn bottom_horizontal(&self) -> char {
+        self.bottom.unwrap_or(self.horizontal)
+    }
+
+    // Joins this frame to dividers and column separators drawn with `inner`. Where the
+    // line weights differ, the T-junctions become mixed-weight glyphs (`┠`, `┯`, `╟`, ...);
+    // where they match, or no such glyph exists, the frame's own junctions are kept
+    pub(crate) fn joined_to(&self, inner: &BorderSet) -> BorderSet {
+        let outer_h = Weight::of_horizontal(self.horizontal);
+        let outer_v = Weight::of_vertical(self.vertical);
+        let inner_h = Weight::of_horizontal(inner.horizontal);
+        let inner_v = Weight::of_vertical(inner.vertical);
+        let join =
+            |own: char, frame: Option<Weight>, line: Option<Weight>, table: [[char; 3]; 3]| {
+                match (frame, line) {
+                    (Some(frame), Some(line)) if frame != line => {
+                        Some(table[frame as usize][line as usize]).filter(|c| *c != ' ')
+                    }
+                    _ => None,
+                }
+                .unwrap_or(own)
+            };
+        BorderSet {
+            // rows: weight of the frame edge; columns: weight of the divider or separator
+            left_t: join(
+                self.left_t,
+                outer_v,
+                inner_h,
+                [['├', '┝', '╞'], ['┠', '┣', ' '], ['╟', ' ', '╠']],
+            ),
+            right_t: join(
+                self.right_t,
+                outer_v,
+                inner_h,
+                [['┤', '┥', '╡'], ['┨', '┫', ' '], ['╢', ' ', '╣']],
+            ),
+            upper_t: join(
+                self.upper_t,
+                outer_h,
+                inner_v,
+                [['┬', '┰', '╥'], ['┯', '┳', ' '], ['╤', ' ', '╦']],
+            ),
+            lower_t: join(
+                self.lower_t,
+                outer_h,
+                inner_v,
+                [['┴', '┸', '╨'], ['┷', '┻', ' '], ['╧', ' ', '╩']],
+            ),
+            ..*self
+        }
+    }
+
+    // The glyph drawn along the right edge
+    pub(crate) fn right_vertical(&self) -> char {
+        self.right.unwrap_or(self.vertical)
+    }
+}
+
+// The ASCII stand-in for a non-ASCII glyph in `slot` (see `BorderSet::to_ascii`)
+fn ascii_glyph(slot: &str, glyph: char) -> char {
+    if glyph.is_ascii() {
+        return glyph;
+    }
+    match slot {
+        "vertical" => '|',
+        "horizontal" if matches!(glyph, '═' | '━' | '┅' | '┉' | '╍') => '=',
+        "horizontal" => '-',
+        _ => '+',
+    }
+}
+
+// Line weight of an edge glyph, for picking mixed-weight junctions. Dashed and dotted
+// lines count as their solid counterparts
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Weight {
+    Light,
+    Heavy,
+    Double,
+}
+
+impl Weight {
+    fn of_horizontal(glyph: char) -> Option<Weight> {
+        match glyph {
+            '─' | '┄' | '┈' | '╌' => Some(Weight::Light),
+            '━' | '┅' | '┉' | '╍' => Some(Weight::Heavy),
+            '═' => Some(Weight::Double),
+            _ => None,
+        }
+    }
+
+    fn of_vertical(glyph: char) -> Option<Weight> {
+        match glyph {
+            '│' | '┆' | '┊' | '╎' => Some(Weight::Light),
+            '┃' | '┇' | '┋' | '╏' => Some(Weight::Heavy),
+            '║' => Some(Weight::Double),
+            _ => None,
+        }
+    }
+}
+
+/// The error returned when a [`BorderSet`] can't be built or fails validation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BorderSetError {
+    /// [`BorderSet::from_glyphs`] was given this many characters instead of eleven.
+    GlyphCount(usize),
+    /// A glyph doesn't occupy exactly one terminal column.
+    Width {
+        /// The field holding the glyph, e.g. `"cross"`
+        slot: &'static str,
+        /// The offending glyph
+        glyph: char,
+        /// Its display width in terminal columns
+        width: usize,
+    },
+}
+
+impl fmt::Display for BorderSetError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BorderSetError::GlyphCount(n) => {
+                write!(f, "expected 11 border glyphs, found {}", n)
+            }
+            BorderSetError::Width { slot, glyph, width } => write!(
+                f,
+                "border glyph {:?} for {} is {} columns wide, expected 1",
+                glyph, slot, width
+            ),
+        }
+    }
 }
 
-pub(crate) const SINGLE_TEMPLATE: BoxTemplates = BoxTemplates {
+impl std::error::Error for BorderSetError {}
+
+pub(crate) const SINGLE_TEMPLATE: BorderSet = BorderSet {
     top_left: '┌',
     top_right: '┐',
     bottom_left: '└',
@@ -26,9 +437,11 @@ pub(crate) const SINGLE_TEMPLATE: BoxTemplates = BoxTemplates {
     upper_t: '┬',
     lower_t: '┴',
     cross: '┼',
+    bottom: None,
+    right: None,
 };
 
-pub(crate) const DOUB_H_TEMPLATE: BoxTemplates = BoxTemplates {
+pub(crate) const DOUB_H_TEMPLATE: BorderSet = BorderSet {
     top_left: '╒',
     top_right: '╕',
     bottom_left: '╘',
@@ -40,9 +453,11 @@ pub(crate) const DOUB_H_TEMPLATE: BoxTemplates = BoxTemplates {
     upper_t: '╤',
     lower_t: '╧',
     cross: '╪',
+    bottom: None,
+    right: None,
 };
 
-pub(crate) const DOUB_V_TEMPLATE: BoxTemplates = BoxTemplates {
+pub(crate) const DOUB_V_TEMPLATE: BorderSet = BorderSet {
     top_right: '╖',
     top_left: '╓',
     bottom_right: '╜',
@@ -54,9 +469,11 @@ pub(crate) const DOUB_V_TEMPLATE: BoxTemplates = BoxTemplates {
     upper_t: '╥',
     lower_t: '╨',
     cross: '╫',
+    bottom: None,
+    right: None,
 };
 
-pub(crate) const DOUBLE_TEMPLATE: BoxTemplates = BoxTemplates {
+pub(crate) const DOUBLE_TEMPLATE: BorderSet = BorderSet {
     top_right: '╗',
     top_left: '╔',
     bottom_right: '╝',
@@ -68,9 +485,11 @@ pub(crate) const DOUBLE_TEMPLATE: BoxTemplates = BoxTemplates {
     upper_t: '╦',
     lower_t: '╩',
     cross: '╬',
+    bottom: None,
+    right: None,
 };
 
-pub(crate) const ROUNDED_TEMPLATE: BoxTemplates = BoxTemplates {
+pub(crate) const ROUNDED_TEMPLATE: BorderSet = BorderSet {
     top_right: '╮',
     top_left: '╭',
     bottom_right: '╯',
@@ -82,9 +501,11 @@ pub(crate) const ROUNDED_TEMPLATE: BoxTemplates = BoxTemplates {
     upper_t: '┬',
     lower_t: '┴',
     cross: '┼',
+    bottom: None,
+    right: None,
 };
 
-pub(crate) const BOLD_TEMPLATE: BoxTemplates = BoxTemplates {
+pub(crate) const BOLD_TEMPLATE: BorderSet = BorderSet {
     top_right: '┓',
     top_left: '┏',
     bottom_right: '┛',
@@ -96,9 +517,11 @@ pub(crate) const BOLD_TEMPLATE: BoxTemplates = BoxTemplates {
     upper_t: '┳',
     lower_t: '┻',
     cross: '╋',
+    bottom: None,
+    right: None,
 };
 
-pub(crate) const CLASSIC_TEMPLATE: BoxTemplates = BoxTemplates {
+pub(crate) const CLASSIC_TEMPLATE: BorderSet = BorderSet {
     top_right: '+',
     top_left: '+',
     bottom_right: '+',
@@ -110,9 +533,27 @@ pub(crate) const CLASSIC_TEMPLATE: BoxTemplates = BoxTemplates {
     upper_t: '+',
     lower_t: '+',
     cross: '+',
+    bottom: None,
+    right: None,
+};
+
+pub(crate) const ASCII_TEMPLATE: BorderSet = BorderSet {
+    top_right: '+',
+    top_left: '+',
+    bottom_right: '+',
+    bottom_left: '+',
+    horizontal: '-',
+    vertical: '|',
+    left_t: '+',
+    right_t: '+',
+    upper_t: '+',
+    lower_t: '+',
+    cross: '+',
+    bottom: None,
+    right: None,
 };
 
-pub(crate) const BOLD_CORNERS_TEMPLATE: BoxTemplates = BoxTemplates {
+pub(crate) const BOLD_CORNERS_TEMPLATE: BorderSet = BorderSet {
     top_right: '┓',
     top_left: '┏',
     bottom_right: '┛',
@@ -124,9 +565,119 @@ pub(crate) const BOLD_CORNERS_TEMPLATE: BoxTemplates = BoxTemplates {
     upper_t: '┬',
     lower_t: '┴',
     cross: '┼',
+    bottom: None,
+    right: None,
+};
+
+pub(crate) const DASHED_TEMPLATE: BorderSet = BorderSet {
+    top_right: '┐',
+    top_left: '┌',
+    bottom_right: '┘',
+    bottom_left: '└',
+    horizontal: '┄',
+    vertical: '┆',
+    left_t: '├',
+    right_t: '┤',
+    upper_t: '┬',
+    lower_t: '┴',
+    cross: '┼',
+    bottom: None,
+    right: None,
+};
+
+pub(crate) const DASHED_BOLD_TEMPLATE: BorderSet = BorderSet {
+    top_right: '┓',
+    top_left: '┏',
+    bottom_right: '┛',
+    bottom_left: '┗',
+    horizontal: '┅',
+    vertical: '┇',
+    left_t: '┣',
+    right_t: '┫',
+    upper_t: '┳',
+    lower_t: '┻',
+    cross: '╋',
+    bottom: None,
+    right: None,
+};
+
+pub(crate) const DOTTED_TEMPLATE: BorderSet = BorderSet {
+    top_right: '┐',
+    top_left: '┌',
+    bottom_right: '┘',
+    bottom_left: '└',
+    horizontal: '┈',
+    vertical: '┊',
+    left_t: '├',
+    right_t: '┤',
+    upper_t: '┬',
+    lower_t: '┴',
+    cross: '┼',
+    bottom: None,
+    right: None,
+};
+
+pub(crate) const DOTTED_BOLD_TEMPLATE: BorderSet = BorderSet {
+    top_right: '┓',
+    top_left: '┏',
+    bottom_right: '┛',
+    bottom_left: '┗',
+    horizontal: '┉',
+    vertical: '┋',
+    left_t: '┣',
+    right_t: '┫',
+    upper_t: '┳',
+    lower_t: '┻',
+    cross: '╋',
+    bottom: None,
+    right: None,
+};
+
+// `▀` along the top and `▄` along the bottom hug the content; dividers keep `▀`
+pub(crate) const BLOCK_TEMPLATE: BorderSet = BorderSet {
+    top_right: '█',
+    top_left: '█',
+    bottom_right: '█',
+    bottom_left: '█',
+    horizontal: '▀',
+    vertical: '█',
+    left_t: '█',
+    right_t: '█',
+    upper_t: '█',
+    lower_t: '█',
+    cross: '█',
+    bottom: Some('▄'),
+    right: None,
+};
+
+// Double frame, single-line dividers and column separators (see
+// `DOUBLE_OUTER_INNER_TEMPLATE`)
+pub(crate) const DOUBLE_OUTER_TEMPLATE: BorderSet = BorderSet {
+    top_right: '╗',
+    top_left: '╔',
+    bottom_right: '╝',
+    bottom_left: '╚',
+    horizontal: '═',
+    vertical: '║',
+    left_t: '╟',
+    right_t: '╢',
+    upper_t: '╤',
+    lower_t: '╧',
+    cross: '┼',
+    bottom: None,
+    right: None,
+};
+
+// The dividers and column separators inside a `DOUBLE_OUTER_TEMPLATE` frame
+pub(crate) const DOUBLE_OUTER_INNER_TEMPLATE: BorderSet = BorderSet {
+    horizontal: '─',
+    vertical: '│',
+    upper_t: '┬',
+    lower_t: '┴',
+    ..DOUBLE_OUTER_TEMPLATE
 };
 
-pub(crate) const EMPTY_TEMPLATE: BoxTemplates = BoxTemplates {
+pub(crate) const EMPTY_TEMPLATE: BorderSet = BorderSet {
     top_right: ' ',
     top_left: ' ',
     bottom_right: ' ',
@@ -138,4 +689,6 @@ pub(crate) const EMPTY_TEMPLATE: BoxTemplates = BoxTemplates {
     upper_t: ' ',
     lower_t: ' ',
     cross: ' ',
+    bottom: None,
+    right: None,
 };
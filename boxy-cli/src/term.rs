@@ -0,0 +1,216 @@
+//! Terminal capability detection.
+//!
+//! Used by [`AsciiFallback::Auto`](crate::AsciiFallback::Auto) to decide whether box-drawing
+//! glyphs can be displayed, or whether a box should be drawn in plain ASCII instead, and
+//! to size boxes to the terminal: see [`TerminalSize`] and
+//! [`Boxy::set_size_provider`](crate::boxer::Boxy::set_size_provider).
+
+#[cfg(unix)]
+use std::sync::Arc;
+#[cfg(unix)]
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::{env, fmt};
+
+/// The size of a terminal, in columns and rows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Size {
+    /// Width in columns
+    pub cols: usize,
+    /// Height in rows
+    pub rows: usize,
+}
+
+/// Something that knows how big the terminal is.
+///
+/// [`Detect`], the default, reads `COLUMNS` and `LINES` before asking the terminal, and
+/// [`Tty`] only asks the terminal. A [`Size`] is a source that always reports itself, and
+/// closures returning `Option<Size>` are size sources too, which lets tests stand in a
+/// terminal of any size, and change it between frames.
+///
+/// # Examples
+///
+/// ```
+/// use std::cell::Cell;
+/// use std::rc::Rc;
+/// use boxy_cli::term::{Size, TerminalSize};
+///
+/// let cols = Rc::new(Cell::new(80));
+/// let fake = {
+///     let cols = Rc::clone(&cols);
+///     move || Some(Size { cols: cols.get(), rows: 24 })
+/// };
+/// cols.set(60);
+/// assert_eq!(fake.size(), Some(Size { cols: 60, rows: 24 }));
+/// ```
+pub trait TerminalSize {
+    /// The current size, or `None` if it can't be told.
+    fn size(&self) -> Option<Size>;
+}
+
+/// The size of the terminal on stdout, as the operating system reports it.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Tty;
+
+impl TerminalSize for Tty {
+    fn size(&self) -> Option<Size> {
+        termsize::get().map(|s| Size {
+            cols: s.cols as usize,
+            rows: s.rows as usize,
+        })
+    }
+}
+
+/// The size from the `COLUMNS` and `LINES` environment variables, else the terminal's,
+/// else an optional fallback.
+///
+/// Each variable counts only if it holds a positive number, and stands in for the terminal's
+/// own value. This lets CI jobs and piped output choose a width (`COLUMNS=100 app | tee
+/// log`), and a fallback width keeps boxes drawn when there is neither a variable nor a
+/// terminal. Without a fallback the size is then unknown, and
+/// [`Boxy::display`](crate::boxer::Boxy::display) prints plain text.
+///
+/// # Examples
+///
+/// ```
+/// use boxy_cli::term::{Detect, TerminalSize};
+///
+/// // always some width: 100 columns when it can't be told
+/// let size = Detect::new().fallback(100).size();
+/// assert!(size.is_some());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Detect {
+    fallback: Option<usize>,
+}
+
+impl Detect {
+    /// Detects the size from the environment and the terminal, without a fallback.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Reports `cols` columns (and 24 rows) when the width can't be told otherwise.
+    pub fn fallback(mut self, cols: usize) -> Self {
+        self.fallback = Some(cols);
+        self
+    }
+}
+
+impl TerminalSize for Detect {
+    fn size(&self) -> Option<Size> {
+        size_from_env(|key| env::var(key).ok(), Tty.size(), self.fallback)
+    }
+}
+
+// Rows reported when only the width is known
+const FALLBACK_ROWS: usize = 24;
+
+// The logic behind `Detect`, over any variable lookup and terminal size so it can be
+// tested without touching the process environment
+pub(crate) fn size_from_env(
+    var: impl Fn(&str) -> Option<String>,
+    tty: Option<Size>,
+    fallback: Option<usize>,
+) -> Option<Size> {
+    let number = |key| {
+        var(key)
+            .and_then(|value| value.trim().parse::<usize>().ok())
+            .filter(|&n| n > 0)
+    };
+    let cols = number("COLUMNS").or(tty.map(|s| s.cols)).or(fallback)?;
+    let rows = number("LINES")
+        .or(tty.map(|s| s.rows))
+        .unwrap_or(FALLBACK_ROWS);
+    Some(Size { cols, rows })
+}
+
+impl TerminalSize for Size {
+    fn size(&self) -> Option<Size> {
+        Some(*self)
+    }
+}
+
+impl<F: Fn() -> Option<Size>> TerminalSize for F {
+    fn size(&self) -> Option<Size> {
+        self()
+    }
+}
+
+// The size source a `Boxy` holds, shared with the threads the box is sent to
+pub(crate) struct Provider(pub(crate) Box<dyn TerminalSize + Send + Sync>);
+
+impl fmt::Debug for Provider {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Provider(..)")
+    }
+}
+
+// Set by SIGWINCH whenever the terminal is resized, until taken
+#[cfg(unix)]
+#[derive(Debug)]
+pub(crate) struct ResizeWatch {
+    flag: Arc<AtomicBool>,
+    id: signal_hook::SigId,
+}
+
+#[cfg(unix)]
+impl ResizeWatch {
+    pub(crate) fn new() -> Option<Self> {
+        let flag = Arc::new(AtomicBool::new(false));
+        let id =
+            signal_hook::flag::register(signal_hook::consts::SIGWINCH, Arc::clone(&flag)).ok()?;
+        Some(Self { flag, id })
+    }
+
+    // Whether the terminal was resized since the last call
+    pub(crate) fn take(&self) -> bool {
+        self.flag.swap(false, Ordering::Relaxed)
+    }
+}
+
+#[cfg(unix)]
+impl Drop for ResizeWatch {
+    fn drop(&mut self) {
+        signal_hook::low_level::unregister(self.id);
+    }
+}
+
+/// Returns `true` if the locale and terminal can display UTF-8 box-drawing glyphs.
+///
+/// `TERM=dumb` and `TERM=linux` (the kernel's virtual console, often reached over serial
+/// lines) are treated as ASCII-only. Otherwise the first non-empty of `LC_ALL`, `LC_CTYPE`
+/// and `LANG` must name a UTF-8 codeset, as in `en_US.UTF-8` or `C.utf8`. With no locale
+/// set at all, Unix systems run in the `C` locale and are treated as ASCII-only, while
+/// Windows consoles are assumed to handle UTF-8.
+///
+/// # Examples
+///
+/// ```
+/// use boxy_cli::term::supports_utf8;
+///
+/// if !supports_utf8() {
+///     eprintln!("falling back to ASCII borders");
+/// }
+/// ```
+pub fn supports_utf8() -> bool {
+    utf8_from_env(|key| env::var(key).ok())
+}
+
+// The detection logic behind `supports_utf8`, over any variable lookup so it can be
+// tested without touching the process environment
+pub(crate) fn utf8_from_env(var: impl Fn(&str) -> Option<String>) -> bool {
+    if matches!(var("TERM").as_deref(), Some("dumb" | "linux")) {
+        return false;
+    }
+    let locale = ["LC_ALL", "LC_CTYPE", "LANG"]
+        .into_iter()
+        .filter_map(&var)
+        .find(|value| !value.is_empty());
+    match locale {
+        Some(locale) => {
+            let locale = locale.to_ascii_lowercase();
+            locale.contains("utf-8") || locale.contains("utf8")
+        }
+        None => cfg!(windows),
+    }
+}
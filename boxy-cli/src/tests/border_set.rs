@@ -0,0 +1,219 @@
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::tests::strip_ansi;
+
+    #[test]
+    fn from_glyphs_fills_fields_in_order() {
+        let set = BorderSet::from_glyphs("abcdefghijk").unwrap();
+        assert_eq!(
+            set.glyphs(),
+            ['a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i', 'j', 'k']
+        );
+        assert_eq!((set.top_left, set.vertical, set.cross), ('a', 'e', 'k'));
+    }
+
+    #[test]
+    fn from_glyphs_rejects_wrong_count() {
+        assert_eq!(
+            BorderSet::from_glyphs("+-|"),
+            Err(BorderSetError::GlyphCount(3))
+        );
+    }
+
+    #[test]
+    fn validate_rejects_wide_zero_width_and_control_glyphs() {
+        let mut set = BorderSet::from_glyphs("┌┐└┘│─├┤┬┴┼").unwrap();
+        set.cross = '日';
+        assert_eq!(
+            set.validate(),
+            Err(BorderSetError::Width {
+                slot: "cross",
+                glyph: '日',
+                width: 2
+            })
+        );
+        set.cross = '\u{0301}';
+        assert!(set.validate().is_err());
+        set.cross = '\t';
+        assert!(set.validate().is_err());
+        assert!(BoxType::custom(set).is_err());
+    }
+
+    #[test]
+    fn custom_type_renders_its_glyphs() {
+        let set = BorderSet::from_glyphs("abcd|=LRUDX").unwrap();
+        let mut b = Boxy::new(BoxType::custom(set).unwrap(), "#ffffff");
+        b.add_text_sgmt("one", "#ffffff", BoxAlign::Left);
+        b.add_col_text_sgmt(BoxAlign::Left, 2);
+        let lines: Vec<String> = b.render(30).iter().map(|l| strip_ansi(l)).collect();
+        assert!(lines[0].starts_with('a') && lines[0].ends_with('b'));
+        assert!(lines[1].starts_with('|'));
+        assert!(lines[2].starts_with('L') && lines[2].contains('U') && lines[2].ends_with('R'));
+        let bottom = lines.last().unwrap();
+        assert!(bottom.starts_with('c') && bottom.contains('D') && bottom.ends_with('d'));
+    }
+
+    #[test]
+    fn macro_keeps_custom_types() {
+        let set = BorderSet::from_glyphs("abcd|=LRUDX").unwrap();
+        let mut b = boxy!(type: BoxType::Custom(set), color: "#ffffff");
+        b.add_text_sgmt("one", "#ffffff", BoxAlign::Left);
+        let lines: Vec<String> = b.render(30).iter().map(|l| strip_ansi(l)).collect();
+        assert!(lines[0].starts_with('a') && lines[0].ends_with('b'));
+        let mut b = boxy!(type: "rounded", color: "#ffffff");
+        b.add_text_sgmt("one", "#ffffff", BoxAlign::Left);
+        assert!(strip_ansi(&b.render(30)[0]).starts_with('╭'));
+    }
+
+    #[test]
+    fn unvalidated_wide_glyphs_fall_back_to_single() {
+        let mut set = BorderSet::from_glyphs("abcd|=LRUDX").unwrap();
+        set.top_left = '🦀';
+        let mut b = Boxy::new(BoxType::Custom(set), "#ffffff");
+        b.add_text_sgmt("one", "#ffffff", BoxAlign::Left);
+        let top = strip_ansi(&b.render(30)[0]);
+        assert!(top.starts_with('┌') && top.ends_with('b'), "{:?}", top);
+    }
+
+    #[test]
+    fn far_edges_default_to_the_near_ones() {
+        let set = BorderSet::from_glyphs("▛▜▙▟▌▀▌▐▀▄█").unwrap();
+        let mut b = Boxy::new(BoxType::Custom(set), "#ffffff");
+        b.add_text_sgmt("one", "#ffffff", BoxAlign::Left);
+        let lines: Vec<String> = b.render(20).iter().map(|l| strip_ansi(l)).collect();
+        assert!(lines[1].starts_with('▌') && lines[1].ends_with('▌'));
+        assert!(lines.last().unwrap().contains('▀'));
+    }
+
+    #[test]
+    fn explicit_far_edges_are_drawn() {
+        let mut set = BorderSet::from_glyphs("▛▜▙▟▌▀▌▐▀▄█").unwrap();
+        set.bottom = Some('▄');
+        set.right = Some('▐');
+        let mut b = Boxy::new(BoxType::Custom(set), "#ffffff");
+        b.add_text_sgmt("one", "#ffffff", BoxAlign::Left);
+        b.add_text_sgmt("two", "#ffffff", BoxAlign::Left);
+        let lines: Vec<String> = b.render(20).iter().map(|l| strip_ansi(l)).collect();
+        assert!(lines[0].contains('▀'));
+        assert!(lines[1].starts_with('▌') && lines[1].ends_with('▐'));
+        assert!(lines[2].contains('▀') && !lines[2].contains('▄'));
+        assert!(lines.last().unwrap().contains('▄'));
+
+        set.right = Some('日');
+        assert!(set.validate().is_err());
+        let ascii = set.to_ascii();
+        assert_eq!((ascii.bottom, ascii.right), (Some('-'), Some('|')));
+    }
+
+    #[test]
+    fn custom_type_displays_as_custom() {
+        let set = BorderSet::from_glyphs("┌┐└┘│─├┤┬┴┼").unwrap();
+        assert_eq!(BoxType::Custom(set).to_string(), "custom");
+    }
+
+    const BUILT_IN: [BoxType; 16] = [
+        BoxType::Classic,
+        BoxType::Ascii,
+        BoxType::Single,
+        BoxType::DoubleHorizontal,
+        BoxType::DoubleVertical,
+        BoxType::Double,
+        BoxType::Bold,
+        BoxType::Rounded,
+        BoxType::BoldCorners,
+        BoxType::Dashed,
+        BoxType::DashedBold,
+        BoxType::Dotted,
+        BoxType::DottedBold,
+        BoxType::Block,
+        BoxType::DoubleOuter,
+        BoxType::Empty,
+    ];
+
+    fn columnar_box(box_type: BoxType) -> Vec<String> {
+        let mut b = Boxy::new(box_type, "#ffffff");
+        b.add_text_sgmt("top", "#ffffff", BoxAlign::Left);
+        b.add_col_text_sgmt(BoxAlign::Left, 2);
+        b.add_col_text_line("left", "#ffffff", &0);
+        b.add_col_text_line("right", "#ffffff", &1);
+        b.add_text_sgmt("bottom", "#ffffff", BoxAlign::Left);
+        b.render(30).iter().map(|l| strip_ansi(l)).collect()
+    }
+
+    #[test]
+    fn built_in_styles_are_one_column_wide() {
+        for box_type in BUILT_IN {
+            let set = crate::boxer::map_box_type(&box_type);
+            assert_eq!(set.validate(), Ok(()), "{}", box_type);
+            let widths: Vec<usize> = columnar_box(box_type.clone())
+                .iter()
+                .map(|l| crate::boxer::display_width(l))
+                .collect();
+            assert!(widths.windows(2).all(|w| w[0] == w[1]), "{}", box_type);
+        }
+    }
+
+    #[test]
+    fn built_in_style_names_round_trip() {
+        for box_type in BUILT_IN {
+            assert_eq!(crate::boxer::resolve_type(box_type.to_string()), box_type);
+        }
+    }
+
+    #[test]
+    fn names_list_every_built_in_style() {
+        let names: Vec<String> = BUILT_IN.iter().map(ToString::to_string).collect();
+        assert_eq!(names, BoxType::NAMES);
+        for name in BoxType::NAMES {
+            assert_eq!(BoxType::from_name(name).unwrap().to_string(), name);
+        }
+    }
+
+    #[test]
+    fn ascii_style_is_pure_ascii() {
+        for line in columnar_box(BoxType::Ascii) {
+            assert!(line.is_ascii(), "{:?}", line);
+        }
+    }
+
+    #[test]
+    fn dashed_styles_use_matching_junctions() {
+        let lines = columnar_box(BoxType::Dashed);
+        assert!(lines[0].starts_with('┌') && lines[0].contains('┄'));
+        assert!(lines[1].starts_with('┆'));
+        assert!(lines[2].starts_with('├') && lines[2].contains('┬'));
+        let lines = columnar_box(BoxType::DottedBold);
+        assert!(lines[0].starts_with('┏') && lines[0].contains('┉'));
+        assert!(lines[2].starts_with('┣') && lines[2].contains('┳'));
+    }
+
+    #[test]
+    fn block_style_uses_a_lower_half_bottom_edge() {
+        let lines = columnar_box(BoxType::Block);
+        assert!(lines[0].starts_with('█') && lines[0].contains('▀'));
+        assert!(lines.last().unwrap().contains('▄'));
+    }
+
+    #[test]
+    fn double_outer_draws_single_lines_inside() {
+        let lines = columnar_box(BoxType::DoubleOuter);
+        assert!(lines[0].starts_with('╔') && lines[0].contains('═'));
+        assert!(lines[1].starts_with('║') && lines[1].ends_with('║'));
+        let divider = &lines[2];
+        assert!(divider.starts_with('╟') && divider.ends_with('╢'));
+        assert!(divider.contains('─') && divider.contains('┬') && !divider.contains('═'));
+        let columns = &lines[3];
+        assert!(columns.starts_with('║') && columns.contains('│') && columns.ends_with('║'));
+        assert!(lines[4].contains('┴'));
+    }
+
+    #[test]
+    fn custom_sets_draw_their_own_dividers() {
+        // the DoubleOuter frame glyphs, without its single-line dividers
+        let set = BorderSet::from_glyphs("╔╗╚╝║═╟╢╤╧┼").unwrap();
+        let lines = columnar_box(BoxType::Custom(set));
+        assert!(lines[2].starts_with('╟') && lines[2].contains('═'));
+        assert!(lines[3].contains('║') && !lines[3].contains('│'));
+    }
+}
@@ -22,7 +22,7 @@ mod tests {
 
     #[test]
     fn builder_render_no_panic() {
-        let mut b = Boxy::builder()
+        let b = Boxy::builder()
             .box_type(BoxType::Double)
             .color("#00ffff")
             .add_segment("Hello", "#ffffff", BoxAlign::Center)
@@ -34,7 +34,7 @@ mod tests {
 
     #[test]
     fn builder_empty_build_no_panic() {
-        let mut b = Boxy::builder().build();
+        let b = Boxy::builder().build();
         let _ = b.render(80);
     }
 }
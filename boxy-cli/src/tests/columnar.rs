@@ -1,24 +1,7 @@
 #[cfg(test)]
 mod tests {
     use crate::prelude::*;
-
-    fn strip_ansi(s: &str) -> String {
-        let mut out = String::new();
-        let mut chars = s.chars().peekable();
-        while let Some(c) = chars.next() {
-            if c == '\x1b' {
-                while let Some(&n) = chars.peek() {
-                    chars.next();
-                    if n == 'm' {
-                        break;
-                    }
-                }
-            } else {
-                out.push(c);
-            }
-        }
-        out
-    }
+    use crate::tests::strip_ansi;
 
     fn visible_len(s: &str) -> usize {
         strip_ansi(s).chars().count()
@@ -119,13 +102,13 @@ mod tests {
     #[test]
     fn col_boundaries_empty_returns_empty() {
         let b = Boxy::new(BoxType::Single, "#ffffff");
-        assert_eq!(b.col_boundaries(&[]), vec![]);
+        assert_eq!(b.col_boundaries(&[]), Vec::<usize>::new());
     }
 
     #[test]
     fn col_boundaries_single_col_returns_empty() {
         let b = Boxy::new(BoxType::Single, "#ffffff");
-        assert_eq!(b.col_boundaries(&[50]), vec![]);
+        assert_eq!(b.col_boundaries(&[50]), Vec::<usize>::new());
     }
 
     #[test]
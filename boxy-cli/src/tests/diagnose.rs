@@ -0,0 +1,156 @@
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    fn text(s: &str) -> Boxy {
+        let mut b = Boxy::new(BoxType::Single, "#00ffff");
+        b.add_text_sgmt(s, "#ffffff", BoxAlign::Left);
+        b
+    }
+
+    #[test]
+    fn fitting_box_has_no_warnings() {
+        let mut b = text("Deployed to production");
+        b.add_col_text_sgmt(BoxAlign::Left, 2);
+        b.add_col_text_line("host", "#ffffff", &0);
+        b.add_col_text_line("status", "#ffffff", &1);
+        assert_eq!(b.diagnose(80), vec![]);
+    }
+
+    #[test]
+    fn narrow_boxes_keep_their_lines_in_the_frame() {
+        for (left, right) in [(0, 0), (0, 1), (1, 0), (1, 1), (2, 1)] {
+            for align in [BoxAlign::Left, BoxAlign::Center, BoxAlign::Right] {
+                for width in left + right + 5..16 {
+                    let mut b = Boxy::new(BoxType::Single, "#00ffff");
+                    b.add_text_sgmt("h abcdefghijklmnopqrstuvwxyz", "#ffffff", align);
+                    b.set_int_padding(BoxPad::from_tldr(0, left, 0, right));
+                    b.set_width(width);
+                    assert_eq!(b.diagnose(40), vec![]);
+                    for line in b.render_plain(40) {
+                        assert_eq!(crate::boxer::display_width(&line), width, "{:?}", line);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn fixed_width_wider_than_the_terminal() {
+        let mut b = text("hi");
+        b.set_width(50);
+        assert_eq!(b.diagnose(50), vec![]);
+        assert_eq!(
+            b.diagnose(49),
+            vec![LayoutWarning::WiderThanTerminal {
+                width: 50,
+                term_width: 49
+            }]
+        );
+        // the shadow and the external padding take up columns too
+        b.set_shadow(Shadow::new(ShadowStyle::Light).offset(2, 1));
+        b.set_ext_padding(BoxPad::from_tldr(0, 3, 0, 0));
+        assert_eq!(
+            b.diagnose(50),
+            vec![LayoutWarning::WiderThanTerminal {
+                width: 55,
+                term_width: 50
+            }]
+        );
+    }
+
+    #[test]
+    fn padding_larger_than_the_box_drops_the_text() {
+        let mut b = text("hello world");
+        b.add_text_line("  ", "#ffffff");
+        b.set_int_padding(BoxPad::vh(0, 6));
+        b.set_width(10);
+        let (lines, warnings) = b.render_with_report(80);
+        // only the borders and the blank row between the two lines are left
+        assert_eq!(lines.len(), 3);
+        assert_eq!(
+            warnings,
+            vec![
+                LayoutWarning::PaddingTooLarge {
+                    padding: 12,
+                    width: 10
+                },
+                LayoutWarning::DroppedContent {
+                    segment: 0,
+                    column: None,
+                    line: 0
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn wide_graphemes_without_room_are_dropped() {
+        let mut b = text("日本");
+        b.set_width(5);
+        b.set_color_mode(ColorMode::Never);
+        let (lines, warnings) = b.render_with_report(80);
+        assert_eq!(lines, vec!["┌───┐", "│   │", "└───┘"]);
+        assert_eq!(
+            warnings,
+            vec![LayoutWarning::DroppedContent {
+                segment: 0,
+                column: None,
+                line: 0
+            }]
+        );
+    }
+
+    #[test]
+    fn zero_width_columns_drop_their_content() {
+        let mut b = Boxy::new(BoxType::Single, "#00ffff");
+        b.add_col_text_sgmt(BoxAlign::Left, 3);
+        b.add_col_text_line("abc", "#ffffff", &0);
+        b.add_col_text_line("abc", "#ffffff", &1);
+        b.add_col_text_line("abc", "#ffffff", &1);
+        b.add_col_text_line("abcdef", "#ffffff", &2);
+        b.set_segment_ratios(0, vec![1, 1, 8]);
+        b.set_width(20);
+        let dropped = |column, line| LayoutWarning::DroppedContent {
+            segment: 0,
+            column: Some(column),
+            line,
+        };
+        assert_eq!(
+            b.diagnose(80),
+            vec![dropped(0, 0), dropped(1, 0), dropped(1, 1)]
+        );
+        assert!(!b.render_plain(80).concat().contains("abc "));
+    }
+
+    #[test]
+    fn columns_narrower_than_their_longest_word() {
+        let mut b = Boxy::new(BoxType::Single, "#00ffff");
+        b.add_text_sgmt("Header", "#ffffff", BoxAlign::Center);
+        b.add_col_text_sgmt(BoxAlign::Left, 2);
+        b.add_col_text_line("a supercalifragilistic word", "#ffffff", &1);
+        b.set_width(24);
+        assert_eq!(
+            b.diagnose(80),
+            vec![LayoutWarning::NarrowColumn {
+                segment: 1,
+                column: 1,
+                width: 7,
+                longest_word: 20
+            }]
+        );
+    }
+
+    #[test]
+    fn warnings_display_what_went_wrong() {
+        let w = LayoutWarning::DroppedContent {
+            segment: 1,
+            column: Some(2),
+            line: 0,
+        };
+        assert_eq!(
+            w.to_string(),
+            "text on line 0 of column 2 in segment 1 doesn't fit and is left out"
+        );
+    }
+}
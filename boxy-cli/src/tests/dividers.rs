@@ -0,0 +1,210 @@
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::tests::strip_ansi;
+
+    // columns / text / columns, so every junction slot is exercised
+    fn mixed_box(outer: BoxType, inner: Option<BoxType>) -> Vec<String> {
+        let mut b = Boxy::new(outer, "#ffffff");
+        if let Some(inner) = inner {
+            b.set_divider_style(inner);
+        }
+        b.add_col_text_sgmt(BoxAlign::Left, 2);
+        b.add_col_text_line("a", "#ffffff", &0);
+        b.add_col_text_line("b", "#ffffff", &1);
+        b.add_text_sgmt("mid", "#ffffff", BoxAlign::Left);
+        b.add_col_text_sgmt(BoxAlign::Left, 2);
+        b.add_col_text_line("c", "#ffffff", &0);
+        b.add_col_text_line("d", "#ffffff", &1);
+        b.render(24).iter().map(|l| strip_ansi(l)).collect()
+    }
+
+    #[test]
+    fn dividers_match_the_frame_by_default() {
+        let lines = mixed_box(BoxType::Bold, None);
+        assert!(lines[2].starts_with('┣') && lines[2].contains('━'));
+        assert!(lines[1].contains('┃'));
+    }
+
+    #[test]
+    fn heavy_frame_with_light_dividers() {
+        let lines = mixed_box(BoxType::Bold, Some(BoxType::Single));
+        assert!(lines[0].starts_with('┏') && lines[0].contains('┯'));
+        assert!(lines[1].starts_with('┃') && lines[1].contains('│'));
+        assert!(lines[2].starts_with('┠') && lines[2].ends_with('┨'));
+        assert!(lines[2].contains('─') && lines[2].contains('┴'));
+        assert!(lines[4].contains('┬'));
+        assert!(lines.last().unwrap().contains('┷'));
+    }
+
+    #[test]
+    fn light_frame_with_double_dividers() {
+        let lines = mixed_box(BoxType::Single, Some(BoxType::Double));
+        assert!(lines[0].contains('╥'));
+        assert!(lines[2].starts_with('╞') && lines[2].ends_with('╡'));
+        assert!(lines[2].contains('╩'));
+        assert!(lines.last().unwrap().contains('╨'));
+    }
+
+    #[test]
+    fn dashed_dividers_join_like_solid_lines() {
+        let lines = mixed_box(BoxType::Double, Some(BoxType::Dashed));
+        assert!(lines[0].contains('╤'));
+        assert!(lines[1].contains('┆'));
+        assert!(lines[2].starts_with('╟') && lines[2].contains('┄'));
+    }
+
+    #[test]
+    fn missing_mixed_glyphs_keep_the_frame_junction() {
+        let lines = mixed_box(BoxType::Bold, Some(BoxType::Double));
+        assert!(lines[0].contains('┳'));
+        assert!(lines[2].starts_with('┣') && lines[2].contains('═'));
+    }
+
+    #[test]
+    fn matching_weights_keep_custom_junctions() {
+        let set = BorderSet::from_glyphs("┌┐└┘│─<>v^+").unwrap();
+        let lines = mixed_box(BoxType::Custom(set), Some(BoxType::Dashed));
+        assert!(lines[0].contains('v'));
+        assert!(lines[2].starts_with('<') && lines[2].ends_with('>'));
+    }
+
+    #[test]
+    fn builder_sets_divider_style() {
+        let b = Boxy::builder()
+            .box_type(BoxType::Double)
+            .divider_style(BoxType::Single)
+            .add_segment("a", "#ffffff", BoxAlign::Left)
+            .add_segment("b", "#ffffff", BoxAlign::Left)
+            .build();
+        let divider = strip_ansi(&b.render(20)[2]);
+        assert!(divider.starts_with('╟') && divider.contains('─'));
+    }
+
+    #[test]
+    fn divider_style_falls_back_to_ascii() {
+        let mut b = Boxy::new(BoxType::Bold, "#ffffff");
+        b.set_divider_style(BoxType::Single);
+        b.set_ascii_fallback(AsciiFallback::Always);
+        b.add_text_sgmt("a", "#ffffff", BoxAlign::Left);
+        b.add_text_sgmt("b", "#ffffff", BoxAlign::Left);
+        let lines: Vec<String> = b.render(20).iter().map(|l| strip_ansi(l)).collect();
+        assert!(lines[0].contains('='));
+        assert!(lines[2].starts_with('+') && lines[2].contains('-'));
+    }
+
+    fn three_segments(dividers: &[Divider]) -> Vec<String> {
+        let mut b = Boxy::new(BoxType::Single, "#ffffff");
+        b.add_text_sgmt("one", "#ffffff", BoxAlign::Left);
+        b.add_text_sgmt("two", "#ffffff", BoxAlign::Left);
+        b.add_text_sgmt("three", "#ffffff", BoxAlign::Left);
+        for (i, divider) in dividers.iter().enumerate() {
+            b.set_divider(i, divider.clone());
+        }
+        b.render(24).iter().map(|l| strip_ansi(l)).collect()
+    }
+
+    #[test]
+    fn unset_boundaries_get_a_rule() {
+        let lines = three_segments(&[]);
+        assert_eq!(lines.len(), 7);
+        assert!(lines[2].starts_with('├') && lines[4].starts_with('├'));
+    }
+
+    #[test]
+    fn none_removes_the_boundary() {
+        let lines = three_segments(&[Divider::None]);
+        assert_eq!(lines.len(), 6);
+        assert!(lines[1].contains("one") && lines[2].contains("two"));
+    }
+
+    #[test]
+    fn spacer_is_a_blank_line() {
+        let lines = three_segments(&[Divider::Rule, Divider::Spacer]);
+        assert_eq!(lines[4].trim_end(), format!("│{}│", " ".repeat(22)));
+    }
+
+    #[test]
+    fn spacer_keeps_continuing_columns() {
+        let mut b = Boxy::new(BoxType::Single, "#ffffff");
+        for _ in 0..2 {
+            b.add_col_text_sgmt(BoxAlign::Left, 2);
+            b.add_col_text_line("a", "#ffffff", &0);
+            b.add_col_text_line("b", "#ffffff", &1);
+        }
+        b.set_divider(0, Divider::Spacer);
+        let spacer = strip_ansi(&b.render(24)[2]);
+        assert_eq!(spacer.matches('│').count(), 3);
+    }
+
+    #[test]
+    fn styled_rule_uses_its_own_glyphs() {
+        let lines = three_segments(&[Divider::Styled(BoxType::Double)]);
+        assert!(lines[2].starts_with('╞') && lines[2].ends_with('╡'));
+        assert!(lines[2].contains('═'));
+        assert!(lines[4].starts_with('├') && lines[4].contains('─'));
+    }
+
+    #[test]
+    fn labeled_rule_centers_the_label() {
+        let lines = three_segments(&[Divider::Labeled("Details".to_string())]);
+        assert_eq!(lines[2], "├────── Details ───────┤");
+        assert_eq!(crate::boxer::display_width(&lines[2]), 24);
+    }
+
+    #[test]
+    fn long_labels_are_truncated() {
+        let lines = three_segments(&[Divider::Labeled("日本語のとても長いラベル".to_string())]);
+        assert_eq!(crate::boxer::display_width(&lines[2]), 24);
+        assert!(lines[2].starts_with("├── 日本") && lines[2].ends_with("──┤"));
+    }
+
+    // 2 columns above the rule and 3 below, so junctions sit at both sets of boundaries
+    fn mismatched_columns(label: &str, width: usize) -> String {
+        let mut b = Boxy::new(BoxType::Single, "#ffffff");
+        for cols in [2, 3] {
+            b.add_col_text_sgmt(BoxAlign::Left, cols);
+            for col in 0..cols {
+                b.add_col_text_line("a", "#ffffff", &col);
+            }
+        }
+        b.set_divider(0, Divider::Labeled(label.to_string()));
+        strip_ansi(&b.render(width)[2])
+    }
+
+    #[test]
+    fn labels_keep_clear_of_junctions() {
+        let rule = mismatched_columns("xx", 24);
+        assert_eq!(rule.matches(['┴', '┬', '┼']).count(), 3);
+        assert!(rule.contains(" xx "));
+        assert_eq!(crate::boxer::display_width(&rule), 24);
+    }
+
+    #[test]
+    fn labels_are_dropped_without_room() {
+        let rule = mismatched_columns("xx", 12);
+        assert!(!rule.contains('x'));
+        assert_eq!(rule.matches(['┴', '┬', '┼']).count(), 3);
+    }
+
+    #[test]
+    fn builder_sets_dividers_before_segments_exist() {
+        let b = Boxy::builder()
+            .segment_divider(1, Divider::None)
+            .add_segment("one", "#ffffff", BoxAlign::Left)
+            .add_segment("two", "#ffffff", BoxAlign::Left)
+            .add_segment("three", "#ffffff", BoxAlign::Left)
+            .segment_divider(0, Divider::Spacer)
+            .build();
+        let lines: Vec<String> = b.render(24).iter().map(|l| strip_ansi(l)).collect();
+        assert_eq!(lines.len(), 6);
+        assert_eq!(lines[2], format!("│{}│", " ".repeat(22)));
+        assert!(lines[4].contains("three"));
+    }
+
+    #[test]
+    #[should_panic(expected = "set_divider: seg_index 3 is out of bounds")]
+    fn set_divider_checks_the_index() {
+        three_segments(&[Divider::Rule, Divider::Rule, Divider::Rule, Divider::Rule]);
+    }
+}
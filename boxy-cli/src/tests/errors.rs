@@ -0,0 +1,240 @@
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    fn columns() -> Boxy {
+        let mut b = Boxy::new(BoxType::Single, "#ffffff");
+        b.add_text_sgmt("Header", "#ffffff", BoxAlign::Center);
+        b.add_col_text_sgmt(BoxAlign::Left, 2);
+        b
+    }
+
+    #[test]
+    fn try_methods_report_bad_indices() {
+        let mut b = columns();
+        assert_eq!(
+            b.try_add_text_line_indx("x", "#ffffff", 5),
+            Err(BoxyError::SegmentIndex { index: 5, count: 2 })
+        );
+        assert_eq!(
+            b.try_add_text_line_indx("x", "#ffffff", 1),
+            Err(BoxyError::NotSingle(1))
+        );
+        assert_eq!(
+            b.try_add_col_text_line_indx("x", "#ffffff", 0, 0),
+            Err(BoxyError::NotColumnar(0))
+        );
+        assert_eq!(
+            b.try_add_col_text_line("x", "#ffffff", 2),
+            Err(BoxyError::ColumnIndex {
+                segment: 1,
+                index: 2,
+                count: 2
+            })
+        );
+        assert_eq!(
+            b.try_set_divider(2, Divider::None),
+            Err(BoxyError::SegmentIndex { index: 2, count: 2 })
+        );
+        assert_eq!(
+            Boxy::new(BoxType::Single, "#ffffff").try_add_text_line("x", "#ffffff"),
+            Err(BoxyError::NoSegments)
+        );
+    }
+
+    #[test]
+    fn try_methods_report_column_mismatches() {
+        let mut b = columns();
+        assert_eq!(
+            b.try_add_col_text_sgmt(BoxAlign::Left, 0),
+            Err(BoxyError::ZeroColumns)
+        );
+        assert_eq!(
+            b.try_set_segment_ratios(1, vec![1, 2, 3]),
+            Err(BoxyError::RatioCount {
+                segment: 1,
+                columns: 2,
+                ratios: 3
+            })
+        );
+        assert_eq!(
+            b.try_set_segment_ratios(0, vec![1]),
+            Err(BoxyError::NotColumnar(0))
+        );
+        assert_eq!(
+            b.try_set_segment_ratios(1, vec![0, 0]),
+            Err(BoxyError::ZeroRatios(1))
+        );
+        assert_eq!(b.try_set_segment_ratios(1, vec![1, 3]), Ok(()));
+        assert_eq!(b.seg_cols_ratio()[1], vec![1, 3]);
+    }
+
+    #[test]
+    fn try_methods_report_invalid_colors() {
+        let mut b = columns();
+        let teal = Err(BoxyError::InvalidColor("teal".to_string()));
+        assert_eq!(b.try_set_color("teal"), teal);
+        assert_eq!(b.try_add_text_sgmt("x", "teal", BoxAlign::Left), teal);
+        assert_eq!(b.try_add_col_text_line("x", "teal", 0), teal);
+        assert_eq!(
+            b.try_set_shadow(Shadow::new(ShadowStyle::Dim).color("teal")),
+            teal
+        );
+        assert_eq!(
+            b.try_set_border_paint(BorderPaint::Sides {
+                top: Some("#ff0000".to_string()),
+                right: Some("teal".to_string()),
+                bottom: None,
+                left: None,
+            }),
+            teal
+        );
+        assert!(Boxy::try_new(BoxType::Single, "teal").is_err());
+    }
+
+    #[test]
+    fn failed_try_methods_leave_the_box_untouched() {
+        let mut b = columns();
+        let before = b.render_plain(30);
+        let _ = b.try_add_text_sgmt("x", "teal", BoxAlign::Left);
+        let _ = b.try_add_col_text_line("x", "#ffffff", 7);
+        let _ = b.try_set_width(4);
+        assert_eq!(b.render_plain(30), before);
+    }
+
+    #[test]
+    fn try_set_width_needs_room_for_text() {
+        let mut b = columns();
+        b.set_padding(BoxPad::new(), BoxPad::uniform(2));
+        assert_eq!(
+            b.try_set_width(8),
+            Err(BoxyError::Width { width: 8, min: 9 })
+        );
+        assert_eq!(b.try_set_width(9), Ok(()));
+        assert!(b.render_plain(30)[1].contains('H'));
+        assert_eq!(b.try_set_width(0), Ok(()));
+    }
+
+    #[test]
+    fn custom_borders_are_validated() {
+        let mut set = BorderSet::from_glyphs("┌┐└┘│─├┤┬┴┼").unwrap();
+        set.cross = '日';
+        let err = Boxy::try_new(BoxType::Custom(set), "#ffffff").unwrap_err();
+        assert!(matches!(err, BoxyError::Border(_)));
+        assert!(std::error::Error::source(&err).is_some());
+        let mut b = columns();
+        assert!(b.try_set_divider_style(BoxType::Custom(set)).is_err());
+    }
+
+    #[test]
+    fn try_build_returns_the_first_builder_error() {
+        let result = Boxy::builder()
+            .add_segment("Header", "#ffffff", BoxAlign::Center)
+            .add_col_line("x", "#ffffff", 0)
+            .add_segment("Body", "teal", BoxAlign::Left)
+            .try_build();
+        assert_eq!(result.unwrap_err(), BoxyError::NotColumnar(0));
+
+        let result = Boxy::builder()
+            .add_col_segment(BoxAlign::Left, 2)
+            .add_line("x", "#ffffff")
+            .try_build();
+        assert_eq!(result.unwrap_err(), BoxyError::NotSingle(0));
+    }
+
+    #[test]
+    fn try_build_validates_the_whole_spec() {
+        let base = || {
+            Boxy::builder()
+                .add_segment("Header", "#ffffff", BoxAlign::Center)
+                .add_col_segment(BoxAlign::Left, 2)
+        };
+        assert!(base().try_build().is_ok());
+        assert_eq!(
+            base().color("teal").try_build().unwrap_err(),
+            BoxyError::InvalidColor("teal".to_string())
+        );
+        assert_eq!(
+            base().width(4).try_build().unwrap_err(),
+            BoxyError::Width { width: 4, min: 5 }
+        );
+        assert_eq!(
+            base().segment_ratios(1, vec![1]).try_build().unwrap_err(),
+            BoxyError::RatioCount {
+                segment: 1,
+                columns: 2,
+                ratios: 1
+            }
+        );
+        assert_eq!(
+            base()
+                .segment_divider(4, Divider::None)
+                .try_build()
+                .unwrap_err(),
+            BoxyError::SegmentIndex { index: 4, count: 2 }
+        );
+        assert_eq!(
+            base()
+                .shadow(Shadow::new(ShadowStyle::Light).color("nope"))
+                .try_build()
+                .unwrap_err(),
+            BoxyError::InvalidColor("nope".to_string())
+        );
+    }
+
+    #[test]
+    fn build_still_falls_back_to_white() {
+        let built = |color: &str| {
+            Boxy::builder()
+                .color(color)
+                .color_mode(ColorMode::Always)
+                .add_segment("x", color, BoxAlign::Left)
+                .build()
+                .render(20)
+        };
+        let white = Boxy::builder()
+            .color_mode(ColorMode::Always)
+            .add_segment("x", "#ffffff", BoxAlign::Left)
+            .build()
+            .render(20);
+        assert_eq!(built("teal")[0], white[0]);
+        assert_ne!(built("teal"), built("#008080"));
+    }
+
+    #[test]
+    #[should_panic(expected = "BoxyBuilder: column_count must be at least 1")]
+    fn build_panics_on_structural_errors() {
+        Boxy::builder().add_col_segment(BoxAlign::Left, 0).build();
+    }
+
+    #[test]
+    #[should_panic(expected = "BoxyBuilder: segment 0 has 3 columns, but 1 ratios were given")]
+    fn build_validates_ratios_before_rendering() {
+        Boxy::builder()
+            .add_col_segment(BoxAlign::Left, 3)
+            .segment_ratios(0, vec![1])
+            .build()
+            .render(40);
+    }
+
+    #[test]
+    #[should_panic(expected = "BoxyBuilder: width 4 is too narrow")]
+    fn build_validates_the_width() {
+        Boxy::builder()
+            .add_segment("x", "#ffffff", BoxAlign::Left)
+            .width(4)
+            .build();
+    }
+
+    #[test]
+    fn errors_display_what_went_wrong() {
+        assert_eq!(
+            BoxyError::SegmentIndex { index: 3, count: 2 }.to_string(),
+            "seg_index 3 is out of bounds (2 segments exist)"
+        );
+        assert_eq!(
+            BoxyError::InvalidColor("teal".to_string()).to_string(),
+            "invalid hex color \"teal\""
+        );
+    }
+}
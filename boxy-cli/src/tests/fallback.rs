@@ -0,0 +1,112 @@
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::term::utf8_from_env;
+    use crate::tests::strip_ansi;
+
+    fn env<'a>(vars: &'a [(&'a str, &'a str)]) -> impl Fn(&str) -> Option<String> + 'a {
+        move |key| {
+            vars.iter()
+                .find(|(k, _)| *k == key)
+                .map(|(_, v)| v.to_string())
+        }
+    }
+
+    #[test]
+    fn utf8_locales_are_detected() {
+        assert!(utf8_from_env(env(&[("LANG", "en_US.UTF-8")])));
+        assert!(utf8_from_env(env(&[("LC_CTYPE", "C.utf8")])));
+        assert!(!utf8_from_env(env(&[("LANG", "C")])));
+        assert!(!utf8_from_env(env(&[("LANG", "POSIX")])));
+    }
+
+    #[test]
+    fn locale_variables_are_checked_in_priority_order() {
+        assert!(!utf8_from_env(env(&[
+            ("LC_ALL", "C"),
+            ("LANG", "en_US.UTF-8")
+        ])));
+        assert!(utf8_from_env(env(&[
+            ("LC_ALL", ""),
+            ("LANG", "en_US.UTF-8")
+        ])));
+    }
+
+    #[test]
+    fn limited_terminals_override_the_locale() {
+        for term in ["dumb", "linux"] {
+            assert!(!utf8_from_env(env(&[
+                ("TERM", term),
+                ("LANG", "en_US.UTF-8")
+            ])));
+        }
+        assert!(utf8_from_env(env(&[
+            ("TERM", "xterm-256color"),
+            ("LANG", "en_US.UTF-8")
+        ])));
+    }
+
+    #[test]
+    fn to_ascii_maps_every_slot() {
+        let heavy = BorderSet::from_glyphs("┏┓┗┛┃━┣┫┳┻╋").unwrap().to_ascii();
+        assert_eq!(
+            heavy.glyphs(),
+            ['+', '+', '+', '+', '|', '=', '+', '+', '+', '+', '+']
+        );
+        let light = BorderSet::from_glyphs("╭╮╰╯┆┄├┤┬┴┼").unwrap().to_ascii();
+        assert_eq!((light.vertical, light.horizontal), ('|', '-'));
+        let kept = BorderSet::from_glyphs("**..:~<>^v#").unwrap();
+        assert_eq!(kept.to_ascii(), kept);
+    }
+
+    #[test]
+    fn always_renders_pure_ascii() {
+        for box_type in [BoxType::Double, BoxType::Block, BoxType::DoubleOuter] {
+            let mut b = Boxy::new(box_type, "#ffffff");
+            b.set_ascii_fallback(AsciiFallback::Always);
+            b.add_text_sgmt("top", "#ffffff", BoxAlign::Left);
+            b.add_col_text_sgmt(BoxAlign::Left, 2);
+            b.add_col_text_line("left", "#ffffff", &0);
+            b.add_col_text_line("right", "#ffffff", &1);
+            for line in b.render(30) {
+                assert!(strip_ansi(&line).is_ascii(), "{:?}", line);
+            }
+        }
+    }
+
+    #[test]
+    fn never_keeps_the_chosen_glyphs() {
+        let b = Boxy::builder()
+            .box_type(BoxType::Rounded)
+            .ascii_fallback(AsciiFallback::Never)
+            .add_segment("Hi", "#ffffff", BoxAlign::Left)
+            .build();
+        assert!(strip_ansi(&b.render(20)[0]).starts_with('╭'));
+    }
+
+    #[test]
+    fn plain_output_ignores_the_locale() {
+        let boxed = |fallback| {
+            let mut b = Boxy::new(BoxType::Rounded, "#ffffff");
+            b.set_ascii_fallback(fallback);
+            b.add_text_sgmt("Hi", "#ffffff", BoxAlign::Left);
+            b.set_divider(0, Divider::Styled(BoxType::Double));
+            b.add_text_sgmt("Bye", "#ffffff", BoxAlign::Left);
+            let plain = b.render_plain(20);
+            assert_eq!(format!("{:#20}", b).lines().collect::<Vec<_>>(), plain);
+            plain
+        };
+        let never = boxed(AsciiFallback::Never);
+        assert!(never[0].starts_with('╭') && never[2].contains('═'));
+        assert_eq!(boxed(AsciiFallback::Auto), never);
+        assert!(boxed(AsciiFallback::Always).iter().all(|l| l.is_ascii()));
+    }
+
+    #[test]
+    fn empty_borders_stay_blank() {
+        let mut b = Boxy::new(BoxType::Empty, "#ffffff");
+        b.set_ascii_fallback(AsciiFallback::Always);
+        b.add_text_sgmt("Hi", "#ffffff", BoxAlign::Left);
+        assert_eq!(strip_ansi(&b.render(20)[0]).trim(), "");
+    }
+}
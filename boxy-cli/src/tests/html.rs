@@ -0,0 +1,72 @@
+#[cfg(all(test, feature = "html"))]
+mod tests {
+    use crate::prelude::*;
+
+    // Drops tags and decodes entities, leaving the text a browser would show
+    fn visible_text(html: &str) -> String {
+        let mut out = String::new();
+        let mut in_tag = false;
+        for c in html.chars() {
+            match c {
+                '<' => in_tag = true,
+                '>' => in_tag = false,
+                c if !in_tag => out.push(c),
+                _ => {}
+            }
+        }
+        out.replace("&lt;", "<")
+            .replace("&gt;", ">")
+            .replace("&quot;", "\"")
+            .replace("&#39;", "'")
+            .replace("&amp;", "&")
+    }
+
+    fn sample() -> Boxy {
+        Boxy::builder()
+            .box_type(BoxType::Double)
+            .color("#00ffff")
+            .add_segment("<b>\"Tom & Jerry's\"</b>", "#ffffff", BoxAlign::Center)
+            .add_col_segment(BoxAlign::Left, 2)
+            .add_col_line("left", "#ff0000", 0)
+            .add_col_line("right", "#00ff00", 1)
+            .build()
+    }
+
+    #[test]
+    fn layout_matches_render() {
+        let b = sample();
+        let html = b.render_html(40);
+        assert!(html.starts_with("<pre class=\"boxy\">") && html.ends_with("</pre>"));
+        assert_eq!(visible_text(&html), b.render_plain(40).join("\n"));
+    }
+
+    #[test]
+    fn text_is_escaped() {
+        let html = sample().render_html(40);
+        assert!(html.contains("&lt;b&gt;&quot;Tom &amp; Jerry&#39;s&quot;&lt;/b&gt;"));
+        assert!(!html.contains("<b>"));
+    }
+
+    #[test]
+    fn colors_become_spans() {
+        let html = sample().render_html(40);
+        assert!(html.contains("<span style=\"color:#00ffff\">╔"));
+        assert!(html.contains("<span style=\"color:#ff0000\">left</span>"));
+    }
+
+    #[test]
+    fn dim_shadows_are_backgrounds() {
+        let mut b = sample();
+        b.set_shadow(Shadow::new(ShadowStyle::Dim).color("#222222"));
+        let html = b.render_html(40);
+        assert!(html.contains("<span style=\"background-color:#222222\">  </span>"));
+        assert_eq!(visible_text(&html), b.render_plain(40).join("\n"));
+    }
+
+    #[test]
+    fn colors_are_written_in_every_color_mode() {
+        let mut b = sample();
+        b.set_color_mode(ColorMode::Never);
+        assert!(b.render_html(40).contains("color:#00ffff"));
+    }
+}
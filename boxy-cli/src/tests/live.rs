@@ -0,0 +1,159 @@
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::term::Size;
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    fn status(lines: &[&str]) -> Boxy {
+        let mut b = Boxy::new(BoxType::Single, "#00ffff");
+        b.set_width(12);
+        b.set_color_mode(ColorMode::Never);
+        fill(&mut b, lines);
+        b
+    }
+
+    fn fill(b: &mut Boxy, lines: &[&str]) {
+        b.clear_segments();
+        for line in lines {
+            b.add_text_sgmt(line, "#ffffff", BoxAlign::Left);
+        }
+    }
+
+    fn text(live: &LiveBox<Vec<u8>>) -> String {
+        String::from_utf8(live.get_ref().clone()).unwrap()
+    }
+
+    #[test]
+    fn first_frame_is_drawn_whole() {
+        let mut live = LiveBox::new(status(&["one"]), Vec::new(), 40);
+        live.draw().unwrap();
+        assert_eq!(
+            text(&live),
+            "\x1b[2K┌──────────┐\n\x1b[2K│ one      │\n\x1b[2K└──────────┘\n"
+        );
+    }
+
+    #[test]
+    fn only_changed_lines_are_redrawn() {
+        let mut live = LiveBox::new(status(&["one", "two"]), Vec::new(), 40);
+        live.draw().unwrap();
+        let first = text(&live).len();
+
+        fill(live.boxy_mut(), &["one", "2"]);
+        live.draw().unwrap();
+        assert_eq!(
+            &text(&live)[first..],
+            "\x1b[5A\x1b[3B\x1b[2K│ 2        │\n\x1b[1B"
+        );
+    }
+
+    #[test]
+    fn unchanged_frame_only_moves_the_cursor() {
+        let mut live = LiveBox::new(status(&["one"]), Vec::new(), 40);
+        live.draw().unwrap();
+        let first = text(&live).len();
+        live.draw().unwrap();
+        assert_eq!(&text(&live)[first..], "\x1b[3A\x1b[3B");
+    }
+
+    #[test]
+    fn shorter_frame_clears_leftover_rows() {
+        let mut live = LiveBox::new(status(&["one", "two"]), Vec::new(), 40);
+        live.draw().unwrap();
+        let first = text(&live).len();
+
+        fill(live.boxy_mut(), &["one"]);
+        live.draw().unwrap();
+        assert_eq!(
+            &text(&live)[first..],
+            "\x1b[5A\x1b[2B\x1b[2K└──────────┘\n\x1b[2K\n\x1b[2K\n\x1b[2A"
+        );
+    }
+
+    #[test]
+    fn append_only_writes_changed_frames_in_full() {
+        let mut live = LiveBox::new(status(&["one"]), Vec::new(), 40);
+        live.set_in_place(false);
+        live.draw().unwrap();
+        live.draw().unwrap();
+        fill(live.boxy_mut(), &["two"]);
+        live.draw().unwrap();
+
+        let out = text(&live);
+        assert!(!out.contains('\x1b'));
+        assert_eq!(out.lines().count(), 6);
+        assert_eq!(out.lines().nth(4), Some("│ two      │"));
+    }
+
+    #[test]
+    fn clear_segments_keeps_the_style() {
+        let mut b = status(&["one", "two"]);
+        b.clear_segments();
+        b.add_text_sgmt("three", "#ffffff", BoxAlign::Left);
+        assert_eq!(
+            b.render(40),
+            vec!["┌──────────┐", "│ three    │", "└──────────┘"]
+        );
+    }
+
+    // A live box following a fake terminal whose width the test controls
+    fn resizable(cols: usize) -> (LiveBox<Vec<u8>>, Rc<Cell<Option<usize>>>) {
+        let mut b = Boxy::new(BoxType::Single, "#00ffff");
+        b.add_text_sgmt("one", "#ffffff", BoxAlign::Left);
+        b.set_color_mode(ColorMode::Never);
+        let width = Rc::new(Cell::new(Some(cols)));
+        let mut live = LiveBox::new(b, Vec::new(), 80);
+        live.set_size_source({
+            let width = Rc::clone(&width);
+            move || width.get().map(|cols| Size { cols, rows: 24 })
+        });
+        (live, width)
+    }
+
+    #[test]
+    fn frames_follow_the_size_source() {
+        let (mut live, _) = resizable(20);
+        assert!(!live.resized());
+        live.draw().unwrap();
+        assert_eq!(
+            text(&live).lines().next(),
+            Some("\x1b[2K┌──────────────────┐")
+        );
+    }
+
+    #[test]
+    fn resize_replaces_the_whole_frame() {
+        let (mut live, width) = resizable(20);
+        live.draw().unwrap();
+        let first = text(&live).len();
+
+        width.set(Some(10));
+        assert!(live.resized());
+        live.draw().unwrap();
+        assert!(!live.resized());
+        // the three 20-column rows now take up two rows each
+        assert_eq!(
+            &text(&live)[first..],
+            "\x1b[6A\x1b[J┌────────┐\n│ one    │\n└────────┘\n"
+        );
+    }
+
+    #[test]
+    fn unknown_size_keeps_the_last_width() {
+        let (mut live, width) = resizable(20);
+        live.draw().unwrap();
+        width.set(None);
+        assert!(!live.resized());
+        live.draw().unwrap();
+        assert!(text(&live).ends_with("\x1b[3A\x1b[3B"));
+    }
+
+    #[test]
+    fn stdout_starts_at_the_size_provider_width() {
+        let mut b = status(&["one"]);
+        b.set_size_provider(Size { cols: 33, rows: 24 });
+        let live = LiveBox::stdout(b);
+        assert!(format!("{:?}", live).contains("term_width: 33"));
+    }
+}
@@ -0,0 +1,132 @@
+#[cfg(all(test, feature = "markdown"))]
+mod tests {
+    use crate::prelude::*;
+
+    fn md(b: &Boxy) -> String {
+        b.render_markdown(&MarkdownOptions::default())
+    }
+
+    #[test]
+    fn single_segments_are_paragraphs() {
+        let mut b = Boxy::new(BoxType::Single, "#ffffff");
+        b.add_text_sgmt("First", "#ffffff", BoxAlign::Left);
+        b.add_text_line("still first", "#ffffff");
+        b.add_text_sgmt("Second", "#ffffff", BoxAlign::Left);
+        assert_eq!(md(&b), "First\\\nstill first\n\nSecond\n");
+    }
+
+    #[test]
+    fn alignment_becomes_div_hints() {
+        let mut b = Boxy::new(BoxType::Single, "#ffffff");
+        b.add_text_sgmt("Centered", "#ffffff", BoxAlign::Center);
+        b.add_text_sgmt("Right", "#ffffff", BoxAlign::Right);
+        assert_eq!(
+            md(&b),
+            "<div align=\"center\">\n\nCentered\n\n</div>\n\n<div align=\"right\">\n\nRight\n\n</div>\n"
+        );
+        let plain = b.render_markdown(&MarkdownOptions::default().align_hints(false));
+        assert_eq!(plain, "Centered\n\nRight\n");
+    }
+
+    #[test]
+    fn titles_and_labels_become_headings() {
+        let mut b = Boxy::new(BoxType::Single, "#ffffff");
+        b.add_text_sgmt("Report", "#ffffff", BoxAlign::Left);
+        b.add_text_sgmt("Body", "#ffffff", BoxAlign::Left);
+        b.set_divider(0, Divider::Labeled("Details".to_string()));
+        let options = MarkdownOptions::default()
+            .title_level(Some(1))
+            .label_level(2);
+        assert_eq!(
+            b.render_markdown(&options),
+            "# Report\n\n## Details\n\nBody\n"
+        );
+    }
+
+    #[test]
+    fn columns_become_gfm_tables() {
+        let b = Boxy::builder()
+            .add_col_segment(BoxAlign::Right, 3)
+            .add_col_line("Name", "#ffffff", 0)
+            .add_col_line("crab", "#ffffff", 0)
+            .add_col_line("ferris", "#ffffff", 0)
+            .add_col_line("Legs", "#ffffff", 1)
+            .add_col_line("10", "#ffffff", 1)
+            .add_col_line("Pipe", "#ffffff", 2)
+            .add_col_line("a|b", "#ffffff", 2)
+            .build();
+        assert_eq!(
+            md(&b),
+            "| Name | Legs | Pipe |\n| ---: | ---: | ---: |\n| crab | 10 | a\\|b |\n| ferris |  |  |\n"
+        );
+        let headless = b.render_markdown(&MarkdownOptions::default().table_header(false));
+        assert!(headless.starts_with("|  |  |  |\n| ---: | ---: | ---: |\n| Name | Legs | Pipe |"));
+    }
+
+    #[test]
+    fn center_tables_use_center_markers() {
+        let b = Boxy::builder()
+            .add_col_segment(BoxAlign::Center, 2)
+            .add_col_line("a", "#ffffff", 0)
+            .add_col_line("b", "#ffffff", 1)
+            .build();
+        assert_eq!(md(&b), "| a | b |\n| :---: | :---: |\n");
+    }
+
+    #[test]
+    fn markdown_syntax_is_escaped() {
+        let mut b = Boxy::new(BoxType::Single, "#ffffff");
+        b.add_text_sgmt("# not a *heading* [link](x) <b>", "#ffffff", BoxAlign::Left);
+        b.add_text_sgmt("- not a list", "#ffffff", BoxAlign::Left);
+        b.add_text_sgmt("1. nor this", "#ffffff", BoxAlign::Left);
+        assert_eq!(
+            md(&b),
+            "\\# not a \\*heading\\* \\[link\\](x) \\<b\\>\n\n\\- not a list\n\n1\\. nor this\n"
+        );
+    }
+
+    #[test]
+    fn colors_map_to_emphasis() {
+        let mut b = Boxy::new(BoxType::Single, "#ffffff");
+        b.add_text_sgmt("error", "#ff0000", BoxAlign::Left);
+        b.add_text_line("note", "#888888");
+        b.add_text_line("run `make`", "#00ff00");
+        b.add_text_line("plain_text", "#ffffff");
+        let options = MarkdownOptions::default()
+            .emphasize("#ff0000", Emphasis::Bold)
+            .emphasize("#888888", Emphasis::Italic)
+            .emphasize("#00FF00", Emphasis::Code);
+        assert_eq!(
+            b.render_markdown(&options),
+            "**error**\\\n_note_\\\n`` run `make` ``\\\nplain\\_text\n"
+        );
+    }
+
+    #[test]
+    fn invalid_emphasis_colors_are_skipped() {
+        let mut b = Boxy::new(BoxType::Single, "#ffffff");
+        b.add_text_sgmt("plain", "#ffffff", BoxAlign::Left);
+        b.add_text_line("loud", "#ff0000");
+        let options = MarkdownOptions::default()
+            .emphasize("teal", Emphasis::Bold)
+            .emphasize("#ff0000", Emphasis::Italic);
+        assert_eq!(b.render_markdown(&options), "plain\\\n_loud_\n");
+    }
+
+    #[test]
+    fn pipes_in_code_cells_are_escaped() {
+        let b = Boxy::builder()
+            .add_col_segment(BoxAlign::Left, 2)
+            .add_col_line("Pattern", "#ffffff", 0)
+            .add_col_line("a|b", "#00ff00", 0)
+            .add_col_line("Text", "#ffffff", 1)
+            .add_col_line("c|d", "#ffffff", 1)
+            .add_segment("x|y", "#00ff00", BoxAlign::Left)
+            .build();
+        let options = MarkdownOptions::default().emphasize("#00ff00", Emphasis::Code);
+        assert_eq!(
+            b.render_markdown(&options),
+            "| Pattern | Text |\n| :--- | :--- |\n| `a\\|b` | c\\|d |\n\n`x|y`\n"
+        );
+    }
+}
@@ -1,9 +1,47 @@
+mod border_set;
 mod builder;
 mod colors;
 mod columnar;
+mod diagnose;
+mod dividers;
+mod errors;
+mod fallback;
+mod html;
+mod live;
+mod markdown;
+mod non_tty;
 mod padding;
+mod paint;
 mod performance;
+mod plain;
 mod render;
 mod segment_api;
+mod shadow;
+mod size;
+mod spec;
+mod stream;
+mod svg;
+mod theme;
 mod unicode;
 mod wrapping;
+mod writers;
+
+// Drops the SGR color escapes from a rendered line
+#[cfg(test)]
+pub(crate) fn strip_ansi(s: &str) -> String {
+    let mut out = String::new();
+    let mut chars = s.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c == '\x1b' {
+            while let Some(&n) = chars.peek() {
+                chars.next();
+                if n == 'm' {
+                    break;
+                }
+            }
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
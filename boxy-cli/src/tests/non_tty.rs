@@ -0,0 +1,71 @@
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    fn report() -> Boxy {
+        let mut b = Boxy::new(BoxType::Single, "#00ffff");
+        b.set_width(20);
+        b.add_text_sgmt("Nightly", "#ffffff", BoxAlign::Center);
+        b.add_col_text_sgmt(BoxAlign::Left, 3);
+        for (col, cells) in [["unit", "docs"], ["passed", "failed"], ["12s", ""]]
+            .iter()
+            .enumerate()
+        {
+            for cell in cells {
+                b.add_col_text_line(cell, "#ffffff", &col);
+            }
+        }
+        b
+    }
+
+    fn written(b: &Boxy, mode: NonTtyMode) -> String {
+        let mut buf = Vec::new();
+        b.write_non_tty(&mut buf, mode).unwrap();
+        String::from_utf8(buf).unwrap()
+    }
+
+    #[test]
+    fn raw_dumps_the_text() {
+        assert_eq!(
+            written(&report(), NonTtyMode::Raw),
+            "Nightly\nunit\ndocs\npassed\nfailed\n12s\n\n"
+        );
+    }
+
+    #[test]
+    fn plain_draws_the_box_without_escapes() {
+        let b = report();
+        let out = written(&b, NonTtyMode::Plain);
+        assert_eq!(out.lines().collect::<Vec<_>>(), b.render_plain(80));
+    }
+
+    #[test]
+    fn full_keeps_the_colors() {
+        let mut b = report();
+        b.set_color_mode(ColorMode::Always);
+        assert!(written(&b, NonTtyMode::Full).contains("\x1b[38;2;0;255;255m"));
+
+        b.set_color_mode(ColorMode::Never);
+        assert!(!written(&b, NonTtyMode::Full).contains('\x1b'));
+    }
+
+    #[test]
+    fn structured_keeps_columns_as_key_values() {
+        assert_eq!(
+            written(&report(), NonTtyMode::Structured),
+            "Nightly\n\nunit: passed, 12s\ndocs: failed\n"
+        );
+    }
+
+    #[test]
+    fn env_names_the_mode() {
+        assert_eq!(NonTtyMode::from_env(Some("plain")), NonTtyMode::Plain);
+        assert_eq!(NonTtyMode::from_env(Some(" FULL ")), NonTtyMode::Full);
+        assert_eq!(
+            NonTtyMode::from_env(Some("structured")),
+            NonTtyMode::Structured
+        );
+        assert_eq!(NonTtyMode::from_env(Some("boxes")), NonTtyMode::Raw);
+        assert_eq!(NonTtyMode::from_env(None), NonTtyMode::Raw);
+    }
+}
@@ -0,0 +1,164 @@
+#[cfg(test)]
+mod tests {
+    use crate::paint::{Line, Paint, Span};
+    use crate::prelude::*;
+    use colored::Color;
+
+    const BASE: Color = Color::TrueColor { r: 1, g: 2, b: 3 };
+    const RED: Color = Color::TrueColor { r: 255, g: 0, b: 0 };
+    const BLUE: Color = Color::TrueColor { r: 0, g: 0, b: 255 };
+
+    fn gradient(direction: GradientDirection) -> Paint {
+        Paint::resolve(&BorderPaint::Gradient {
+            direction,
+            stops: vec!["#ff0000".to_string(), "#0000ff".to_string()],
+        })
+    }
+
+    #[test]
+    fn solid_uses_the_box_color() {
+        assert_eq!(Paint::Solid.color_at(BASE, (3, 4), (10, 6)), BASE);
+    }
+
+    #[test]
+    fn horizontal_gradient_runs_left_to_right() {
+        let paint = gradient(GradientDirection::Horizontal);
+        assert_eq!(paint.color_at(BASE, (0, 3), (11, 5)), RED);
+        assert_eq!(paint.color_at(BASE, (10, 0), (11, 5)), BLUE);
+        assert_eq!(
+            paint.color_at(BASE, (5, 2), (11, 5)),
+            Color::TrueColor {
+                r: 128,
+                g: 0,
+                b: 128
+            }
+        );
+    }
+
+    #[test]
+    fn vertical_gradient_runs_top_to_bottom() {
+        let paint = gradient(GradientDirection::Vertical);
+        assert_eq!(paint.color_at(BASE, (7, 0), (11, 5)), RED);
+        assert_eq!(paint.color_at(BASE, (0, 4), (11, 5)), BLUE);
+    }
+
+    #[test]
+    fn perimeter_gradient_loops_back_to_the_start() {
+        let paint = gradient(GradientDirection::Perimeter);
+        // the far corner is halfway round the frame
+        assert_eq!(paint.color_at(BASE, (10, 4), (11, 5)), BLUE);
+        assert_eq!(paint.color_at(BASE, (0, 0), (11, 5)), RED);
+        // the left edge is on its way back to red
+        let Color::TrueColor { r, b, .. } = paint.color_at(BASE, (0, 1), (11, 5)) else {
+            panic!("expected a true color");
+        };
+        assert!(r > 200 && b < 60);
+    }
+
+    #[test]
+    fn multi_stop_gradients_hit_every_stop() {
+        let paint = Paint::resolve(&BorderPaint::Gradient {
+            direction: GradientDirection::Horizontal,
+            stops: vec!["#ff0000".into(), "#00ff00".into(), "#0000ff".into()],
+        });
+        assert_eq!(
+            paint.color_at(BASE, (5, 0), (11, 3)),
+            Color::TrueColor { r: 0, g: 255, b: 0 }
+        );
+    }
+
+    #[test]
+    fn degenerate_gradients() {
+        let empty = Paint::resolve(&BorderPaint::Gradient {
+            direction: GradientDirection::Horizontal,
+            stops: Vec::new(),
+        });
+        assert_eq!(empty.color_at(BASE, (2, 0), (5, 3)), BASE);
+        let single = Paint::resolve(&BorderPaint::Gradient {
+            direction: GradientDirection::Vertical,
+            stops: vec!["#ff0000".into()],
+        });
+        assert_eq!(single.color_at(BASE, (2, 2), (5, 3)), RED);
+        // a one-column box doesn't divide by zero
+        assert_eq!(
+            gradient(GradientDirection::Perimeter).color_at(BASE, (0, 0), (1, 1)),
+            RED
+        );
+    }
+
+    #[test]
+    fn sides_color_their_edges_and_leave_the_rest() {
+        let paint = Paint::resolve(&BorderPaint::Sides {
+            top: Some("#0000ff".into()),
+            right: None,
+            bottom: None,
+            left: Some("#ff0000".into()),
+        });
+        // corners belong to the left and right sides
+        assert_eq!(paint.color_at(BASE, (0, 0), (10, 5)), RED);
+        assert_eq!(paint.color_at(BASE, (9, 0), (10, 5)), BASE);
+        assert_eq!(paint.color_at(BASE, (4, 0), (10, 5)), BLUE);
+        assert_eq!(paint.color_at(BASE, (4, 4), (10, 5)), BASE);
+        // dividers and separators inside use the box color
+        assert_eq!(paint.color_at(BASE, (4, 2), (10, 5)), BASE);
+    }
+
+    #[test]
+    fn lines_merge_neighbouring_spans() {
+        let mut line = Line::new();
+        line.edge(3, '│');
+        line.space(1);
+        line.space(2);
+        line.text("hi", BASE);
+        line.text("", BASE);
+        line.borders(['─', '┤']);
+        assert_eq!(
+            line.spans,
+            vec![
+                Span::Space(2),
+                Span::Border("│".to_string()),
+                Span::Space(3),
+                Span::Text("hi".to_string(), BASE),
+                Span::Border("─┤".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn paint_does_not_change_the_text() {
+        let mut plain = Boxy::new(BoxType::Double, "#ffffff");
+        plain.add_text_sgmt("Banner", "#ffffff", BoxAlign::Center);
+        plain.add_col_text_sgmt(BoxAlign::Left, 2);
+        plain.add_col_text_line("a", "#ffffff", &0);
+        let mut painted = Boxy::new(BoxType::Double, "#ffffff");
+        painted.add_text_sgmt("Banner", "#ffffff", BoxAlign::Center);
+        painted.add_col_text_sgmt(BoxAlign::Left, 2);
+        painted.add_col_text_line("a", "#ffffff", &0);
+        painted.set_border_paint(BorderPaint::Gradient {
+            direction: GradientDirection::Perimeter,
+            stops: vec!["#ff0000".into(), "#00ff00".into(), "#0000ff".into()],
+        });
+        let strip = |lines: Vec<String>| -> Vec<String> {
+            lines
+                .iter()
+                .map(|l| {
+                    let mut out = String::new();
+                    let mut chars = l.chars();
+                    while let Some(c) = chars.next() {
+                        if c == '\x1b' {
+                            for n in chars.by_ref() {
+                                if n == 'm' {
+                                    break;
+                                }
+                            }
+                        } else {
+                            out.push(c);
+                        }
+                    }
+                    out
+                })
+                .collect()
+        };
+        assert_eq!(strip(plain.render(30)), strip(painted.render(30)));
+    }
+}
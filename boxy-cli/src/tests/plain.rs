@@ -0,0 +1,67 @@
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::tests::strip_ansi;
+
+    fn fancy(mode: ColorMode) -> Boxy {
+        Boxy::builder()
+            .box_type(BoxType::Rounded)
+            .color("#00ffff")
+            .color_mode(mode)
+            .border_paint(BorderPaint::Gradient {
+                direction: GradientDirection::Perimeter,
+                stops: vec!["#ff0000".to_string(), "#0000ff".to_string()],
+            })
+            .shadow(Shadow::new(ShadowStyle::Dim))
+            .add_segment("Title", "#ffff00", BoxAlign::Center)
+            .add_segment("Body", "#ffffff", BoxAlign::Left)
+            .width(16)
+            .build()
+    }
+
+    #[test]
+    fn render_plain_has_no_escapes() {
+        let lines = fancy(ColorMode::Always).render_plain(40);
+        assert!(lines.iter().all(|l| !l.contains('\x1b')));
+        assert_eq!(lines[0], "╭──────────────╮");
+        assert_eq!(lines[1], "│    Title     │  ");
+    }
+
+    #[test]
+    fn always_emits_true_color_escapes() {
+        let lines = fancy(ColorMode::Always).render(40);
+        assert!(lines[0].starts_with("\x1b[38;2;255;0;0m╭"));
+        assert!(lines[1].contains("\x1b[38;2;255;255;0mTitle\x1b[0m"));
+        // the dim shadow is a background color
+        assert!(lines[1].contains("\x1b[48;2;85;85;85m  \x1b[0m"));
+    }
+
+    #[test]
+    fn modes_differ_only_in_escapes() {
+        let colored = fancy(ColorMode::Always).render(40);
+        let plain = fancy(ColorMode::Never).render(40);
+        let stripped: Vec<String> = colored.iter().map(|l| strip_ansi(l)).collect();
+        assert_eq!(stripped, plain);
+    }
+
+    #[test]
+    fn never_mode_applies_to_the_write_apis() {
+        let b = fancy(ColorMode::Never);
+        let mut bytes = Vec::new();
+        b.write_to(&mut bytes, 40).unwrap();
+        let mut text = String::new();
+        b.fmt_into(&mut text, 40).unwrap();
+        assert_eq!(String::from_utf8(bytes).unwrap(), text);
+        assert_eq!(text.lines().collect::<Vec<_>>(), b.render_plain(40));
+    }
+
+    #[test]
+    fn alternate_display_is_plain() {
+        let b = fancy(ColorMode::Always);
+        assert_eq!(
+            format!("{:#40}", b).lines().collect::<Vec<_>>(),
+            b.render_plain(40)
+        );
+        assert!(format!("{:40}", b).contains('\x1b'));
+    }
+}
@@ -1,24 +1,7 @@
 #[cfg(test)]
 mod tests {
     use crate::prelude::*;
-
-    fn strip_ansi(s: &str) -> String {
-        let mut out = String::new();
-        let mut chars = s.chars().peekable();
-        while let Some(c) = chars.next() {
-            if c == '\x1b' {
-                while let Some(&n) = chars.peek() {
-                    chars.next();
-                    if n == 'm' {
-                        break;
-                    }
-                }
-            } else {
-                out.push(c);
-            }
-        }
-        out
-    }
+    use crate::tests::strip_ansi;
 
     fn visible_len(s: &str) -> usize {
         strip_ansi(s).chars().count()
@@ -33,7 +16,7 @@ mod tests {
 
     #[test]
     fn render_empty_box_no_panic() {
-        let mut b = Boxy::new(BoxType::Single, "#00ffff");
+        let b = Boxy::new(BoxType::Single, "#00ffff");
         assert!(b.render(80).len() >= 2);
     }
 
@@ -0,0 +1,84 @@
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::tests::strip_ansi;
+
+    fn shadowed(shadow: Shadow) -> Boxy {
+        let mut b = Boxy::new(BoxType::Single, "#ffffff");
+        b.set_shadow(shadow);
+        b.set_int_padding(BoxPad::new());
+        b.add_text_sgmt("Hi", "#ffffff", BoxAlign::Left);
+        b
+    }
+
+    #[test]
+    fn shadow_is_cast_right_and_below() {
+        let mut b = shadowed(Shadow::new(ShadowStyle::Light));
+        b.set_width(8);
+        let lines: Vec<String> = b.render(40).iter().map(|l| strip_ansi(l)).collect();
+        assert_eq!(
+            lines,
+            vec!["┌──────┐", "│ Hi   │░░", "└──────┘░░", "  ░░░░░░░░"]
+        );
+    }
+
+    #[test]
+    fn offset_and_style_are_configurable() {
+        let mut b = shadowed(Shadow::new(ShadowStyle::Medium).offset(1, 2));
+        b.set_width(8);
+        let lines: Vec<String> = b.render(40).iter().map(|l| strip_ansi(l)).collect();
+        assert_eq!(lines.len(), 5);
+        assert!(!lines[1].contains('▒'));
+        assert_eq!(lines[2], "└──────┘▒");
+        assert_eq!(lines[3], " ▒▒▒▒▒▒▒▒");
+        assert_eq!(lines[4], " ▒▒▒▒▒▒▒▒");
+    }
+
+    #[test]
+    fn dim_shadow_is_blank_cells() {
+        let mut b = shadowed(Shadow::new(ShadowStyle::Dim));
+        b.set_width(8);
+        let lines: Vec<String> = b.render(40).iter().map(|l| strip_ansi(l)).collect();
+        assert_eq!(lines[1], "│ Hi   │  ");
+        assert_eq!(lines[3], "          ");
+    }
+
+    #[test]
+    fn dynamic_width_leaves_room_for_the_shadow() {
+        let b = shadowed(Shadow::default());
+        let lines: Vec<String> = b.render(30).iter().map(|l| strip_ansi(l)).collect();
+        // the top border is the only line the shadow doesn't reach
+        assert_eq!(lines[0].chars().count(), 28);
+        for line in &lines[1..] {
+            assert_eq!(line.chars().count(), 30, "{:?}", line);
+        }
+    }
+
+    #[test]
+    fn centered_boxes_are_centered_with_their_shadow() {
+        let mut b = shadowed(Shadow::new(ShadowStyle::Light).offset(4, 1));
+        b.set_width(10);
+        b.set_align(BoxAlign::Center);
+        let lines: Vec<String> = b.render(40).iter().map(|l| strip_ansi(l)).collect();
+        // 10 columns of box plus 4 of shadow leave 26 columns, 13 on each side
+        let line = &lines[1];
+        let lead = line.chars().take_while(|c| *c == ' ').count();
+        let trail = 40 - line.chars().count();
+        assert_eq!((lead, trail), (13, 13), "{:?}", line);
+        assert_eq!(lines[3].chars().take_while(|c| *c == ' ').count(), 17);
+    }
+
+    #[test]
+    fn asymmetric_padding_does_not_underflow() {
+        let mut b = shadowed(Shadow::default());
+        b.set_align(BoxAlign::Center);
+        b.set_ext_padding(BoxPad::from_tldr(0, 30, 0, 0));
+        b.set_width(20);
+        let lines = b.render(40);
+        assert!(strip_ansi(&lines[0]).trim_start().starts_with('┌'));
+
+        b.set_align(BoxAlign::Right);
+        b.set_width(50);
+        assert!(strip_ansi(&b.render(40)[0]).trim_start().starts_with('┌'));
+    }
+}
@@ -0,0 +1,89 @@
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::term::{Detect, Size, TerminalSize, size_from_env};
+
+    fn env<'a>(vars: &'a [(&'a str, &'a str)]) -> impl Fn(&str) -> Option<String> + 'a {
+        move |key| {
+            vars.iter()
+                .find(|(k, _)| *k == key)
+                .map(|(_, v)| v.to_string())
+        }
+    }
+
+    const TTY: Option<Size> = Some(Size {
+        cols: 120,
+        rows: 40,
+    });
+
+    #[test]
+    fn env_vars_come_before_the_terminal() {
+        assert_eq!(
+            size_from_env(env(&[("COLUMNS", "100"), ("LINES", "30")]), TTY, None),
+            Some(Size {
+                cols: 100,
+                rows: 30
+            })
+        );
+        // each variable stands in for its own dimension only
+        assert_eq!(
+            size_from_env(env(&[("COLUMNS", "100")]), TTY, None),
+            Some(Size {
+                cols: 100,
+                rows: 40
+            })
+        );
+        assert_eq!(size_from_env(env(&[]), TTY, Some(80)), TTY);
+    }
+
+    #[test]
+    fn bad_env_values_are_ignored() {
+        for value in ["", "0", "-5", "wide"] {
+            assert_eq!(
+                size_from_env(env(&[("COLUMNS", value)]), TTY, None),
+                TTY,
+                "COLUMNS={:?}",
+                value
+            );
+        }
+        assert_eq!(
+            size_from_env(env(&[("COLUMNS", " 90 ")]), None, None).map(|s| s.cols),
+            Some(90)
+        );
+    }
+
+    #[test]
+    fn fallback_only_without_env_or_terminal() {
+        assert_eq!(size_from_env(env(&[]), None, None), None);
+        assert_eq!(
+            size_from_env(env(&[]), None, Some(100)),
+            Some(Size {
+                cols: 100,
+                rows: 24
+            })
+        );
+        assert!(Detect::new().fallback(100).size().is_some());
+    }
+
+    #[test]
+    fn provider_sizes_display() {
+        let mut b = Boxy::new(BoxType::Single, "#00ffff");
+        b.add_text_sgmt("hi", "#ffffff", BoxAlign::Left);
+        b.set_color_mode(ColorMode::Never);
+        b.set_size_provider(Size { cols: 30, rows: 10 });
+        assert_eq!(format!("{}", b).lines().collect::<Vec<_>>(), b.render(30));
+
+        // a formatter width still wins
+        assert_eq!(
+            format!("{:20}", b).lines().collect::<Vec<_>>(),
+            b.render(20)
+        );
+
+        let b = Boxy::builder()
+            .size_provider(|| Some(Size { cols: 24, rows: 10 }))
+            .color_mode(ColorMode::Never)
+            .add_segment("hi", "#ffffff", BoxAlign::Left)
+            .build();
+        assert_eq!(format!("{}", b).lines().collect::<Vec<_>>(), b.render(24));
+    }
+}
@@ -0,0 +1,181 @@
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use crate::prelude::*;
+
+    fn sample() -> Boxy {
+        let mut b = Boxy::builder()
+            .box_type(BoxType::DoubleOuter)
+            .color("#FF8800")
+            .align(BoxAlign::Center)
+            .padding(BoxPad::uniform(1), BoxPad::vh(0, 2))
+            .width(40)
+            .add_segment("Status", "#ffffff", BoxAlign::Center)
+            .add_line("all good", "#00ff00")
+            .add_col_segment(BoxAlign::Left, 2)
+            .add_col_line("cpu", "#aaaaaa", 0)
+            .add_col_line("12%", "#ffffff", 1)
+            .segment_ratios(1, vec![2, 1])
+            .segment_divider(0, Divider::Labeled("Load".to_string()))
+            .shadow(Shadow::new(ShadowStyle::Medium).offset(1, 1))
+            .border_paint(BorderPaint::Gradient {
+                direction: GradientDirection::Vertical,
+                stops: vec!["#ff0000".to_string(), "#0000ff".to_string()],
+            })
+            .build();
+        b.set_color_mode(ColorMode::Always);
+        b
+    }
+
+    #[test]
+    fn json_round_trip_renders_the_same() {
+        let b = sample();
+        let json = serde_json::to_string(&b).unwrap();
+        let back: Boxy = serde_json::from_str(&json).unwrap();
+        assert_eq!(back.render(60), b.render(60));
+        assert_eq!(back.to_spec(), b.to_spec());
+    }
+
+    #[test]
+    fn colors_are_hex_strings() {
+        let value = serde_json::to_value(sample()).unwrap();
+        assert_eq!(value["color"], "#ff8800");
+        assert_eq!(value["type"], "double_outer");
+        assert_eq!(value["segments"][0]["colors"]["single"][1], "#00ff00");
+        assert_eq!(value["segments"][1]["ratios"], serde_json::json!([2, 1]));
+        assert_eq!(value["segments"][0]["divider"]["labeled"], "Load");
+        assert_eq!(value["shadow"]["style"], "medium");
+        assert_eq!(value["border_paint"]["gradient"]["stops"][1], "#0000ff");
+    }
+
+    #[test]
+    fn custom_border_sets_round_trip() {
+        let set = BorderSet::from_glyphs("┌┐└┘│─├┤┬┴┼").unwrap();
+        let mut b = Boxy::new(BoxType::Custom(set), "#ffffff");
+        b.add_text_sgmt("x", "#ffffff", BoxAlign::Left);
+        let json = serde_json::to_string(&b).unwrap();
+        let back: Boxy = serde_json::from_str(&json).unwrap();
+        assert_eq!(back.render(20), b.render(20));
+    }
+
+    #[test]
+    fn building_blocks_serialize_alone() {
+        assert_eq!(
+            serde_json::to_string(&BoxAlign::Right).unwrap(),
+            "\"right\""
+        );
+        assert_eq!(
+            serde_json::from_str::<BoxPad>(r#"{"left": 2}"#).unwrap(),
+            BoxPad::from_tldr(0, 2, 0, 0)
+        );
+        assert_eq!(
+            serde_json::from_str::<SegType>(r#"{"columnar": [["a"], []]}"#).unwrap(),
+            SegType::Columnar(vec![vec!["a".to_string()], vec![]])
+        );
+    }
+
+    #[test]
+    fn unknown_keys_are_rejected() {
+        let err = serde_json::from_str::<Boxy>(r##"{"colour": "#ffffff"}"##).unwrap_err();
+        assert!(
+            err.to_string().starts_with("unknown field `colour`"),
+            "{}",
+            err
+        );
+        let err = serde_json::from_str::<BoxySpec>(
+            r#"{"segments": [{"content": {"single": ["x"]}, "allign": "left"}]}"#,
+        )
+        .unwrap_err();
+        assert!(
+            err.to_string().contains("unknown field `allign`"),
+            "{}",
+            err
+        );
+        let err = serde_json::from_str::<BoxPad>(r#"{"bottom": 1}"#).unwrap_err();
+        assert!(
+            err.to_string().contains("unknown field `bottom`"),
+            "{}",
+            err
+        );
+        let err = serde_json::from_str::<BoxType>(r#""wavy""#).unwrap_err();
+        assert!(
+            err.to_string().contains("unknown variant `wavy`"),
+            "{}",
+            err
+        );
+    }
+
+    #[test]
+    fn invalid_specs_name_the_key() {
+        let spec = |json: &str| serde_json::from_str::<BoxySpec>(json).unwrap();
+        let err = Boxy::from_spec(&spec(
+            r##"{"segments": [{"content": {"single": ["a", "b"]}, "colors": {"single": ["#fff", "nope"]}}]}"##,
+        ))
+        .unwrap_err();
+        assert_eq!(err.key(), "segments[0].colors[1]");
+
+        let err = Boxy::from_spec(&spec(
+            r##"{"segments": [{"content": {"single": ["a"]}, "colors": {"single": []}}]}"##,
+        ))
+        .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "segments[0].colors: expected 1 colors, found 0"
+        );
+
+        let err = Boxy::from_spec(&spec(
+            r##"{"segments": [{"content": {"columnar": [["a"]]}, "ratios": [1, 2]}]}"##,
+        ))
+        .unwrap_err();
+        assert_eq!(err.key(), "segments[0].ratios");
+
+        let err = Boxy::from_spec(&spec(
+            r##"{"segments": [{"content": {"single": ["a"]}, "ratios": [1]}]}"##,
+        ))
+        .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "segments[0].ratios: segment 0 is not a columnar segment"
+        );
+
+        let err = Boxy::from_spec(&spec(
+            r##"{"segments": [{"content": {"columnar": [["a"], ["b"]]}, "ratios": [0, 0]}]}"##,
+        ))
+        .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "segments[0].ratios: the column ratios of segment 0 add up to 0"
+        );
+
+        let err = Boxy::from_spec(&spec(r##"{"width": 2}"##)).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "width: width 2 is too narrow, the box needs at least 5 columns"
+        );
+
+        let err =
+            Boxy::from_spec(&spec(r##"{"segments": [{"content": {"single": []}}]}"##)).unwrap_err();
+        assert_eq!(err.key(), "segments[0].content");
+
+        let err = Boxy::from_spec(&spec(r##"{"shadow": {"color": "#12345"}}"##)).unwrap_err();
+        assert_eq!(err.key(), "shadow.color");
+
+        // errors surface through Deserialize for Boxy, too
+        let err = serde_json::from_str::<Boxy>(r##"{"color": "red"}"##).unwrap_err();
+        assert!(
+            err.to_string().starts_with("color: invalid hex color"),
+            "{}",
+            err
+        );
+    }
+
+    #[test]
+    fn missing_colors_default_to_white() {
+        let spec: BoxySpec =
+            serde_json::from_str(r##"{"segments": [{"content": {"single": ["hi"]}}]}"##).unwrap();
+        let b = Boxy::from_spec(&spec).unwrap();
+        assert_eq!(
+            b.to_spec().segments[0].colors,
+            Some(SegmentColors::Single(vec!["#ffffff".to_string()]))
+        );
+    }
+}
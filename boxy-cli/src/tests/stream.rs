@@ -0,0 +1,194 @@
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use std::io::Write;
+
+    fn header() -> Boxy {
+        let mut b = Boxy::new(BoxType::Rounded, "#00ffff");
+        b.add_text_sgmt("Deploy", "#ffffff", BoxAlign::Center);
+        b.set_width(24);
+        b.set_color_mode(ColorMode::Never);
+        b
+    }
+
+    fn text(buf: &[u8]) -> String {
+        String::from_utf8(buf.to_vec()).unwrap()
+    }
+
+    #[test]
+    fn top_is_written_before_any_text() {
+        let mut buf = Vec::new();
+        let bw = BoxWriter::new(header(), &mut buf, 40).unwrap();
+        assert_eq!(text(bw.get_ref()).lines().count(), 2);
+        drop(bw);
+        assert_eq!(
+            text(&buf),
+            "╭──────────────────────╮\n│        Deploy        │\n╰──────────────────────╯\n"
+        );
+    }
+
+    #[test]
+    fn lines_are_drawn_once_complete() {
+        let mut bw = BoxWriter::new(header(), Vec::new(), 40).unwrap();
+        write!(bw, "step 1").unwrap();
+        assert_eq!(text(bw.get_ref()).lines().count(), 2);
+        write!(bw, " ok\nstep 2").unwrap();
+        let out = text(bw.get_ref());
+        assert_eq!(out.lines().count(), 4);
+        assert_eq!(out.lines().nth(3), Some("│ step 1 ok            │"));
+
+        // the unfinished line comes out with the bottom border
+        let out = text(&bw.finish().unwrap());
+        assert_eq!(out.lines().nth(4), Some("│ step 2               │"));
+        assert_eq!(out.lines().nth(5), Some("╰──────────────────────╯"));
+    }
+
+    #[test]
+    fn matches_a_box_of_undivided_segments() {
+        let mut bw = BoxWriter::new(header(), Vec::new(), 40).unwrap();
+        writeln!(bw, "compiling boxy-cli with a long line that wraps").unwrap();
+        writeln!(bw, "tests ok\r").unwrap();
+        bw.new_segment(Divider::Labeled("result".to_string()))
+            .unwrap();
+        bw.set_align(BoxAlign::Right);
+        writeln!(bw, "done").unwrap();
+        let streamed = text(&bw.finish().unwrap());
+
+        let mut b = header();
+        b.add_text_sgmt(
+            "compiling boxy-cli with a long line that wraps",
+            "#ffffff",
+            BoxAlign::Left,
+        );
+        b.add_text_sgmt("tests ok", "#ffffff", BoxAlign::Left);
+        b.add_text_sgmt("done", "#ffffff", BoxAlign::Right);
+        b.set_divider(1, Divider::None);
+        b.set_divider(2, Divider::Labeled("result".to_string()));
+        let expected: String = b.render(40).iter().map(|l| format!("{}\n", l)).collect();
+        assert_eq!(streamed, expected);
+    }
+
+    #[test]
+    fn trailing_segment_break_adds_nothing() {
+        let mut bw = BoxWriter::new(header(), Vec::new(), 40).unwrap();
+        writeln!(bw, "only line").unwrap();
+        bw.new_segment(Divider::Rule).unwrap();
+        let out = text(&bw.finish().unwrap());
+        assert_eq!(out.lines().count(), 5);
+        assert!(out.lines().last().unwrap().starts_with('╰'));
+    }
+
+    fn columns() -> Boxy {
+        let mut b = Boxy::new(BoxType::Single, "#00ffff");
+        b.add_col_text_sgmt(BoxAlign::Left, 2);
+        b.add_col_text_line("a", "#ffffff", &0);
+        b.add_col_text_line("b", "#ffffff", &1);
+        b.set_width(20);
+        b.set_color_mode(ColorMode::Never);
+        b
+    }
+
+    #[test]
+    fn columns_above_join_the_next_rule() {
+        let expected: String = columns()
+            .render(40)
+            .iter()
+            .map(|l| format!("{}\n", l))
+            .collect();
+        let bw = BoxWriter::new(columns(), Vec::new(), 40).unwrap();
+        assert_eq!(text(&bw.finish().unwrap()), expected);
+
+        let mut bw = BoxWriter::new(columns(), Vec::new(), 40).unwrap();
+        writeln!(bw, "below").unwrap();
+        let out = text(&bw.finish().unwrap());
+        let lines: Vec<&str> = out.lines().collect();
+        assert!(lines[0].contains('┬'));
+        assert_eq!(lines[2], "├────────┴─────────┤");
+        assert_eq!(lines[4], "└──────────────────┘");
+    }
+
+    // Accepts `limit` bytes, then fails every write; flushing fails if `flush_fails`
+    struct Limited {
+        buf: Vec<u8>,
+        limit: usize,
+        flush_fails: bool,
+    }
+
+    impl Write for Limited {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            let room = self.limit.saturating_sub(self.buf.len());
+            if room == 0 {
+                return Err(std::io::Error::other("full"));
+            }
+            let n = room.min(buf.len());
+            self.buf.extend_from_slice(&buf[..n]);
+            Ok(n)
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            match self.flush_fails {
+                true => Err(std::io::Error::other("flush")),
+                false => Ok(()),
+            }
+        }
+    }
+
+    #[test]
+    fn failed_finish_draws_one_bottom_border() {
+        let mut out = Limited {
+            buf: Vec::new(),
+            limit: usize::MAX,
+            flush_fails: true,
+        };
+        let mut bw = BoxWriter::new(header(), &mut out, 40).unwrap();
+        writeln!(bw, "step 1").unwrap();
+        assert!(bw.finish().is_err());
+        assert_eq!(text(&out.buf).matches('╰').count(), 1);
+    }
+
+    #[test]
+    fn write_reports_the_lines_drawn_before_an_error() {
+        let mut bw = BoxWriter::new(header(), Vec::new(), 40).unwrap();
+        writeln!(bw, "one").unwrap();
+        let limit = bw.get_ref().len();
+
+        let mut out = Limited {
+            buf: Vec::new(),
+            limit,
+            flush_fails: false,
+        };
+        let mut bw = BoxWriter::new(header(), &mut out, 40).unwrap();
+        bw.write_all(b"o").unwrap();
+        assert_eq!(bw.write(b"ne\ntwo\n").unwrap(), 3);
+        assert!(bw.write(b"two\n").is_err());
+        drop(bw);
+        assert!(text(&out.buf).ends_with("│ one                  │\n"));
+    }
+
+    #[test]
+    fn streamed_text_is_colored() {
+        let mut b = Boxy::new(BoxType::Single, "#00ffff");
+        b.set_color_mode(ColorMode::Always);
+        let mut bw = BoxWriter::new(b, Vec::new(), 20).unwrap();
+        bw.set_color("#ff0000");
+        writeln!(bw, "red").unwrap();
+        let out = text(&bw.finish().unwrap());
+        assert!(out.contains("\x1b[38;2;255;0;0mred\x1b[0m"));
+        assert!(out.contains("\x1b[38;2;0;255;255m"));
+    }
+
+    #[test]
+    fn try_set_color_rejects_invalid_colors() {
+        let mut b = Boxy::new(BoxType::Single, "#00ffff");
+        b.set_color_mode(ColorMode::Always);
+        let mut bw = BoxWriter::new(b, Vec::new(), 20).unwrap();
+        assert_eq!(bw.try_set_color("#ff0000"), Ok(()));
+        assert_eq!(
+            bw.try_set_color("teal"),
+            Err(BoxyError::InvalidColor("teal".to_string()))
+        );
+        writeln!(bw, "red").unwrap();
+        let out = text(&bw.finish().unwrap());
+        assert!(out.contains("\x1b[38;2;255;0;0mred\x1b[0m"));
+    }
+}
@@ -0,0 +1,105 @@
+#[cfg(all(test, feature = "svg"))]
+mod tests {
+    use crate::prelude::*;
+
+    // (x, y, text) of every <text> element, unescaped
+    fn texts(svg: &str) -> Vec<(f64, f64, String)> {
+        let attr = |el: &str, name: &str| -> f64 {
+            let start = el.find(&format!(" {}=\"", name)).unwrap() + name.len() + 3;
+            el[start..start + el[start..].find('"').unwrap()]
+                .parse()
+                .unwrap()
+        };
+        svg.lines()
+            .filter(|l| l.starts_with("<text "))
+            .map(|l| {
+                let body = &l[l.find('>').unwrap() + 1..l.rfind("</text>").unwrap()];
+                let text = body
+                    .replace("&lt;", "<")
+                    .replace("&gt;", ">")
+                    .replace("&quot;", "\"")
+                    .replace("&#39;", "'")
+                    .replace("&amp;", "&");
+                (attr(l, "x"), attr(l, "y"), text)
+            })
+            .collect()
+    }
+
+    fn sample() -> Boxy {
+        Boxy::builder()
+            .box_type(BoxType::Rounded)
+            .color("#00ffff")
+            .add_segment("a < b & 世界", "#ffffff", BoxAlign::Left)
+            .add_segment("second", "#ff0000", BoxAlign::Right)
+            .width(20)
+            .build()
+    }
+
+    // The text elements placed back on a character grid
+    fn regrid(svg: &str, cell: (f64, f64), rows: usize) -> Vec<String> {
+        let mut grid = vec![String::new(); rows];
+        for (x, y, text) in texts(svg) {
+            let row = &mut grid[(y / cell.1) as usize];
+            let col = (x / cell.0).round() as usize;
+            let width = unicode_width::UnicodeWidthStr::width(row.as_str());
+            row.push_str(&" ".repeat(col - width));
+            row.push_str(&text);
+        }
+        grid
+    }
+
+    #[test]
+    fn same_grid_as_render() {
+        let b = sample();
+        let options = SvgOptions::default().cell_size(10.0, 20.0);
+        let plain = b.render_plain(30);
+        let grid = regrid(&b.render_svg(30, &options), (10.0, 20.0), plain.len());
+        let trimmed: Vec<&str> = plain.iter().map(|l| l.trim_end()).collect();
+        assert_eq!(grid, trimmed);
+    }
+
+    #[test]
+    fn image_size_follows_the_cells() {
+        let svg = sample().render_svg(30, &SvgOptions::default().cell_size(10.0, 20.0));
+        // 20 columns, 5 rows: top, text, divider, text, bottom
+        assert!(svg.contains(" width=\"200\" height=\"100\" viewBox=\"0 0 200 100\""));
+    }
+
+    #[test]
+    fn options_are_applied() {
+        let options = SvgOptions::default()
+            .font("Fira Code", 16.0)
+            .background(Some("#002b36"));
+        let svg = sample().render_svg(30, &options);
+        assert!(svg.contains("font-family=\"Fira Code\" font-size=\"16\""));
+        assert!(svg.contains("<rect width=\"100%\" height=\"100%\" fill=\"#002b36\"/>"));
+
+        let svg = sample().render_svg(30, &SvgOptions::default().background(None));
+        assert!(!svg.contains("<rect"));
+    }
+
+    #[test]
+    fn colors_and_escaping() {
+        let svg = sample().render_svg(30, &SvgOptions::default());
+        assert!(svg.contains("fill=\"#00ffff\""));
+        assert!(svg.contains("fill=\"#ff0000\""));
+        assert!(svg.contains(">a &lt; b &amp; 世界</text>"));
+    }
+
+    #[test]
+    fn wide_characters_take_two_cells() {
+        let svg = sample().render_svg(30, &SvgOptions::default().cell_size(10.0, 20.0));
+        // "a < b & 世界" is 12 columns wide
+        assert!(svg.contains("textLength=\"120\" lengthAdjust=\"spacingAndGlyphs\">a &lt;"));
+    }
+
+    #[test]
+    fn dim_shadows_are_filled_cells() {
+        let mut b = sample();
+        b.set_shadow(Shadow::new(ShadowStyle::Dim).color("#333333"));
+        let svg = b.render_svg(30, &SvgOptions::default().cell_size(10.0, 20.0));
+        assert!(
+            svg.contains("<rect x=\"200\" y=\"20\" width=\"20\" height=\"20\" fill=\"#333333\"/>")
+        );
+    }
+}
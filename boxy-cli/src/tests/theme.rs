@@ -0,0 +1,148 @@
+#[cfg(all(test, feature = "config"))]
+mod tests {
+    use crate::prelude::*;
+    use crate::tests::strip_ansi;
+
+    const TOML_THEMES: &str = r##"
+        [styles.brand]
+        base = "bold"
+        top_left = "▛"
+        top_right = "▜"
+
+        [themes.alert]
+        type = "brand"
+        color = "#ff5555"
+        text_color = "#ffffff"
+        align = "center"
+        text_align = "right"
+        internal_padding = [1, 2]
+        external_padding = { top = 1, left = 2, down = 1, right = 2 }
+
+        [themes.calm]
+        type = "rounded"
+
+        [themes.calm.glyphs]
+        horizontal = "~"
+        bottom = "_"
+    "##;
+
+    #[test]
+    fn toml_themes_parse_and_expose_getters() {
+        let themes = ThemeSet::from_toml_str(TOML_THEMES).unwrap();
+        assert_eq!(themes.names().collect::<Vec<_>>(), vec!["alert", "calm"]);
+        let alert = themes.get("alert").unwrap();
+        assert!(matches!(
+            alert.box_type(),
+            Some(BoxType::Custom(set)) if set.top_left == '▛' && set.horizontal == '━'
+        ));
+        assert_eq!(alert.color(), Some("#ff5555"));
+        assert_eq!(alert.text_color(), Some("#ffffff"));
+        assert_eq!(alert.text_align(), Some(BoxAlign::Right));
+    }
+
+    #[test]
+    fn style_glyphs_are_rendered() {
+        let themes = ThemeSet::from_toml_str(TOML_THEMES).unwrap();
+        let b = Boxy::builder()
+            .theme(themes.get("alert").unwrap())
+            .add_segment("Hi", "#ffffff", BoxAlign::Left)
+            .build();
+        let lines = b.render(40);
+        let top = strip_ansi(&lines[0]);
+        assert!(top.contains('▛') && top.contains('▜') && top.contains('━'));
+        assert!(strip_ansi(lines.last().unwrap()).contains('┗'));
+    }
+
+    #[test]
+    fn inline_glyphs_override_the_type() {
+        let themes = ThemeSet::from_toml_str(TOML_THEMES).unwrap();
+        let mut b = Boxy::new(BoxType::Single, "#ffffff");
+        themes.get("calm").unwrap().apply(&mut b);
+        b.add_text_sgmt("Hi", "#ffffff", BoxAlign::Left);
+        let lines = b.render(20);
+        let top = strip_ansi(&lines[0]);
+        assert!(top.starts_with('╭') && top.contains('~'), "{:?}", top);
+        assert!(strip_ansi(lines.last().unwrap()).contains('_'));
+    }
+
+    #[test]
+    fn set_type_after_theme_drops_custom_glyphs() {
+        let themes = ThemeSet::from_toml_str(TOML_THEMES).unwrap();
+        let mut b = Boxy::new(BoxType::Single, "#ffffff");
+        themes.get("calm").unwrap().apply(&mut b);
+        b.set_type(BoxType::Double);
+        assert!(strip_ansi(&b.render(20)[0]).starts_with('╔'));
+    }
+
+    #[test]
+    fn json_themes_match_toml() {
+        let json = r##"{"themes": {"alert": {"type": "double", "internal_padding": {"top": 1, "left": 3}}}}"##;
+        let themes = ThemeSet::from_json_str(json).unwrap();
+        assert_eq!(
+            themes.get("alert").unwrap().box_type(),
+            Some(&BoxType::Double)
+        );
+    }
+
+    #[test]
+    fn invalid_color_names_the_key() {
+        let err = ThemeSet::from_toml_str("[themes.bad]\ncolor = \"#zzzzzz\"").unwrap_err();
+        assert_eq!(err.key(), Some("themes.bad.color"));
+        assert!(
+            err.to_string()
+                .starts_with("themes.bad.color: invalid hex color")
+        );
+    }
+
+    #[test]
+    fn unknown_keys_are_rejected() {
+        let err = ThemeSet::from_toml_str("[themes.bad]\ncolour = \"#ffffff\"").unwrap_err();
+        assert_eq!(err.key(), Some("themes.bad.colour"));
+        let err = ThemeSet::from_toml_str("[themes.bad.glyphs]\ncorner = \"+\"").unwrap_err();
+        assert_eq!(err.key(), Some("themes.bad.glyphs.corner"));
+        let err = ThemeSet::from_json_str(r#"{"theme": {}}"#).unwrap_err();
+        assert_eq!(err.key(), Some("theme"));
+    }
+
+    #[test]
+    fn bad_values_name_the_key() {
+        let err = ThemeSet::from_toml_str("[themes.bad]\ntype = \"wavy\"").unwrap_err();
+        assert_eq!(err.key(), Some("themes.bad.type"));
+        let err = ThemeSet::from_toml_str("[themes.bad]\nalign = \"middle\"").unwrap_err();
+        assert_eq!(err.key(), Some("themes.bad.align"));
+        let err =
+            ThemeSet::from_toml_str("[themes.bad]\nexternal_padding = [1, 2, 3]").unwrap_err();
+        assert_eq!(err.key(), Some("themes.bad.external_padding"));
+        let err = ThemeSet::from_toml_str("[themes.bad]\ninternal_padding = [1, -2]").unwrap_err();
+        assert_eq!(err.key(), Some("themes.bad.internal_padding[1]"));
+        let err = ThemeSet::from_toml_str("[styles.bad]\ncross = \"++\"").unwrap_err();
+        assert_eq!(err.key(), Some("styles.bad.cross"));
+        let err = ThemeSet::from_toml_str("[themes.bad.glyphs]\ncross = \"🦀\"").unwrap_err();
+        assert_eq!(err.key(), Some("themes.bad.glyphs.cross"));
+    }
+
+    #[test]
+    fn syntax_errors_are_reported() {
+        let err = ThemeSet::from_json_str("{\"themes\": ").unwrap_err();
+        assert!(matches!(err, ThemeError::Syntax { format: "JSON", .. }));
+        assert!(err.key().is_none());
+    }
+
+    #[test]
+    fn load_rejects_unknown_extensions() {
+        let err = ThemeSet::load("themes.yaml").unwrap_err();
+        assert!(matches!(err, ThemeError::UnknownFormat(_)));
+    }
+
+    #[test]
+    fn load_reads_toml_files() {
+        let path = std::env::temp_dir().join(format!("boxy-theme-{}.toml", std::process::id()));
+        std::fs::write(&path, "[themes.file]\ntype = \"bold\"").unwrap();
+        let themes = ThemeSet::load(&path);
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(
+            themes.unwrap().get("file").unwrap().box_type(),
+            Some(&BoxType::Bold)
+        );
+    }
+}
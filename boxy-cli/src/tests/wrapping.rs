@@ -57,4 +57,11 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn wrap_skips_graphemes_wider_than_a_line() {
+        // one column of room, and every character two wide
+        let result = text_wrap_vec_fast("日本 a", 3, &BoxPad::new());
+        assert_eq!(result, vec!["", "a"]);
+    }
 }
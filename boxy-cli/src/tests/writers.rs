@@ -0,0 +1,97 @@
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use std::{fmt, io};
+
+    fn sample() -> Boxy {
+        let mut b = Boxy::new(BoxType::Single, "#00ffff");
+        b.add_text_sgmt("Hello", "#ffffff", BoxAlign::Center);
+        b.add_text_sgmt("World", "#ffffff", BoxAlign::Left);
+        b
+    }
+
+    // Accepts `limit` bytes, then fails every write
+    struct Failing {
+        limit: usize,
+        written: usize,
+    }
+
+    impl io::Write for Failing {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            if self.written + buf.len() > self.limit {
+                return Err(io::Error::new(io::ErrorKind::BrokenPipe, "closed"));
+            }
+            self.written += buf.len();
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    impl fmt::Write for Failing {
+        fn write_str(&mut self, s: &str) -> fmt::Result {
+            io::Write::write(self, s.as_bytes())
+                .map(|_| ())
+                .map_err(|_| fmt::Error)
+        }
+    }
+
+    #[test]
+    fn write_to_matches_render() {
+        let b = sample();
+        let mut buf = Vec::new();
+        b.write_to(&mut buf, 40).unwrap();
+        let expected: String = b.render(40).iter().map(|l| format!("{}\n", l)).collect();
+        assert_eq!(String::from_utf8(buf).unwrap(), expected);
+    }
+
+    #[test]
+    fn fmt_into_appends_to_a_string() {
+        let b = sample();
+        let mut out = String::from("header\n");
+        b.fmt_into(&mut out, 40).unwrap();
+        let expected: String = b.render(40).iter().map(|l| format!("{}\n", l)).collect();
+        assert_eq!(out, format!("header\n{}", expected));
+    }
+
+    #[test]
+    fn io_errors_are_propagated() {
+        let mut out = Failing {
+            limit: 0,
+            written: 0,
+        };
+        let err = sample().write_to(&mut out, 40).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::BrokenPipe);
+    }
+
+    #[test]
+    fn rendering_stops_at_the_first_error() {
+        let b = sample();
+        let first = b.render(40)[0].len() + 1;
+        let mut out = Failing {
+            limit: first,
+            written: 0,
+        };
+        assert!(b.write_to(&mut out, 40).is_err());
+        assert_eq!(out.written, first);
+    }
+
+    #[test]
+    fn fmt_errors_are_propagated() {
+        let mut out = Failing {
+            limit: 10,
+            written: 0,
+        };
+        assert_eq!(sample().fmt_into(&mut out, 40), Err(fmt::Error));
+    }
+
+    #[test]
+    fn writers_can_be_unsized() {
+        let mut buf = Vec::new();
+        let out: &mut dyn io::Write = &mut buf;
+        sample().write_to(out, 40).unwrap();
+        assert!(!buf.is_empty());
+    }
+}
@@ -0,0 +1,647 @@
+//! Loading themes and border styles from TOML or JSON files.
+//!
+//! Available with the `config` cargo feature. A theme file keeps the look of your boxes —
+//! border type, colors, padding, alignment and custom border glyphs — outside your binary,
+//! so several tools can share one definition.
+//!
+//! # File format
+//!
+//! A file has two optional top-level tables:
+//!
+//! - `styles` — named border styles: a built-in `base` type plus any glyph overrides
+//! - `themes` — named themes that [`Theme::apply`] onto a [`Boxy`] or
+//!   [`BoxyBuilder::theme`] uses while building
+//!
+//! ```toml
+//! [styles.brand]
+//! base = "bold"
+//! top_left = "▛"
+//! top_right = "▜"
+//!
+//! [themes.alert]
+//! type = "brand"              # a built-in type name or a style from [styles]
+//! color = "#ff5555"
+//! text_color = "#ffffff"
+//! align = "center"            # box alignment in the terminal
+//! text_align = "left"         # alignment to use for segments
+//! internal_padding = [1, 2]   # [vertical, horizontal]
+//! external_padding = 1        # uniform
+//!
+//! [themes.alert.glyphs]
+//! horizontal = "═"
+//! ```
+//!
+//! The same structure works in JSON (`{"themes": {"alert": {"type": "bold", ...}}}`).
+//!
+//! Padding accepts a single number (uniform), a `[vertical, horizontal]` pair, a
+//! `[top, left, down, right]` array (the [`BoxPad::from_tldr`] order), or a table with any
+//! of `top`, `left`, `down` and `right`. Glyph keys are the [`BorderSet`] fields: `top_left`,
+//! `top_right`, `bottom_left`, `bottom_right`, `vertical`, `horizontal`, `left_t`, `right_t`,
+//! `upper_t`, `lower_t`, `cross`, and the optional `bottom` and `right` edges.
+//!
+//! Every key is optional — applying a theme only changes the settings it defines. Unknown
+//! keys and bad values are rejected with a [`ThemeError`] naming the offending key.
+//!
+//! # Examples
+//!
+//! ```
+//! use boxy_cli::prelude::*;
+//!
+//! let themes = ThemeSet::from_toml_str(r##"
+//!     [themes.ok]
+//!     type = "rounded"
+//!     color = "#32cd32"
+//!     text_color = "#ffffff"
+//! "##).unwrap();
+//!
+//! let ok = themes.get("ok").unwrap();
+//! Boxy::builder()
+//!     .theme(ok)
+//!     .add_segment("Deploy finished", ok.text_color().unwrap_or("#ffffff"), BoxAlign::Center)
+//!     .build()
+//!     .display();
+//! ```
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use hex_color::HexColor;
+use serde_json::{Map, Value};
+
+use crate::boxer::map_box_type;
+use crate::boxer::{Boxy, BoxyBuilder};
+use crate::constructs::{BoxAlign, BoxPad, BoxType};
+use crate::templates::{BorderSet, BorderSetError};
+
+const THEME_KEYS: [&str; 8] = [
+    "type",
+    "color",
+    "text_color",
+    "align",
+    "text_align",
+    "internal_padding",
+    "external_padding",
+    "glyphs",
+];
+
+/// A named look for a box, loaded from a theme file.
+///
+/// Every setting is optional; [`apply`](Self::apply) only touches the ones the theme defines.
+/// Segment-level settings (`text_color`, `text_align`) can't be applied to the box as a whole,
+/// so they're exposed through getters to pass along when adding segments.
+///
+/// # Examples
+///
+/// ```
+/// use boxy_cli::prelude::*;
+///
+/// let themes = ThemeSet::from_json_str(r##"{"themes": {"warn": {"type": "bold", "color": "#ffaa00"}}}"##).unwrap();
+///
+/// let mut b = Boxy::new(BoxType::Single, "#ffffff");
+/// themes.get("warn").unwrap().apply(&mut b);
+/// b.add_text_sgmt("Disk almost full", "#ffffff", BoxAlign::Left);
+/// b.display();
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Theme {
+    box_type: Option<BoxType>,
+    color: Option<String>,
+    text_color: Option<String>,
+    align: Option<BoxAlign>,
+    text_align: Option<BoxAlign>,
+    int_padding: Option<BoxPad>,
+    ext_padding: Option<BoxPad>,
+}
+
+impl Theme {
+    /// Applies every setting defined by this theme to an existing [`Boxy`].
+    ///
+    /// Settings the theme doesn't define are left untouched.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use boxy_cli::prelude::*;
+    ///
+    /// let themes = ThemeSet::from_toml_str("[themes.plain]\ntype = \"double\"").unwrap();
+    /// let mut b = Boxy::new(BoxType::Single, "#00ffff");
+    /// themes.get("plain").unwrap().apply(&mut b); // now double-bordered, still cyan
+    /// ```
+    pub fn apply(&self, boxy: &mut Boxy) {
+        if let Some(box_type) = &self.box_type {
+            boxy.set_type(box_type.clone());
+        }
+        if let Some(color) = &self.color {
+            boxy.set_color(color);
+        }
+        if let Some(align) = self.align {
+            boxy.set_align(align);
+        }
+        if let Some(pad) = self.int_padding {
+            boxy.set_int_padding(pad);
+        }
+        if let Some(pad) = self.ext_padding {
+            boxy.set_ext_padding(pad);
+        }
+    }
+
+    /// The border type this theme selects, if any.
+    ///
+    /// Themes using a named style or inline glyphs report a [`BoxType::Custom`].
+    pub fn box_type(&self) -> Option<&BoxType> {
+        self.box_type.as_ref()
+    }
+
+    /// The border color (a hex code) this theme selects, if any.
+    pub fn color(&self) -> Option<&str> {
+        self.color.as_deref()
+    }
+
+    /// The text color (a hex code) this theme suggests for segments, if any.
+    pub fn text_color(&self) -> Option<&str> {
+        self.text_color.as_deref()
+    }
+
+    /// The text alignment this theme suggests for segments, if any.
+    pub fn text_align(&self) -> Option<BoxAlign> {
+        self.text_align
+    }
+}
+
+impl BoxyBuilder {
+    /// Applies a [`Theme`] loaded from a theme file to the builder.
+    ///
+    /// Only the settings the theme defines are changed, so calls after this one can still
+    /// override individual settings.
+    ///
+    /// # Returns
+    ///
+    /// The builder instance for method chaining
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use boxy_cli::prelude::*;
+    ///
+    /// let themes = ThemeSet::from_toml_str("[themes.info]\ntype = \"rounded\"\ncolor = \"#00ffff\"").unwrap();
+    /// let b = Boxy::builder()
+    ///     .theme(themes.get("info").unwrap())
+    ///     .width(40) // still overridable afterwards
+    ///     .add_segment("Hello", "#ffffff", BoxAlign::Center)
+    ///     .build();
+    /// ```
+    pub fn theme(mut self, theme: &Theme) -> Self {
+        if let Some(box_type) = &theme.box_type {
+            self = self.box_type(box_type.clone());
+        }
+        if let Some(color) = &theme.color {
+            self = self.color(color);
+        }
+        if let Some(align) = theme.align {
+            self = self.align(align);
+        }
+        if let Some(pad) = theme.int_padding {
+            self = self.internal_padding(pad);
+        }
+        if let Some(pad) = theme.ext_padding {
+            self = self.external_padding(pad);
+        }
+        self
+    }
+}
+
+/// A collection of named [`Theme`]s parsed from a theme file.
+///
+/// # Examples
+///
+/// ```
+/// use boxy_cli::prelude::*;
+///
+/// let themes = ThemeSet::from_toml_str(r##"
+///     [themes.ok]
+///     color = "#32cd32"
+///
+///     [themes.fail]
+///     color = "#ff5555"
+/// "##).unwrap();
+///
+/// assert_eq!(themes.names().collect::<Vec<_>>(), vec!["fail", "ok"]);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ThemeSet {
+    themes: BTreeMap<String, Theme>,
+}
+
+impl ThemeSet {
+    /// Reads a theme file from disk, picking the format from its extension
+    /// (`.toml` or `.json`).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ThemeError::Io`] if the file can't be read, [`ThemeError::UnknownFormat`]
+    /// for any other extension, and the parse errors of
+    /// [`from_toml_str`](Self::from_toml_str) / [`from_json_str`](Self::from_json_str).
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, ThemeError> {
+        let path = path.as_ref();
+        let is_ext = |ext: &str| {
+            path.extension()
+                .is_some_and(|e| e.eq_ignore_ascii_case(ext))
+        };
+        let parse: fn(&str) -> Result<Self, ThemeError> = if is_ext("toml") {
+            Self::from_toml_str
+        } else if is_ext("json") {
+            Self::from_json_str
+        } else {
+            return Err(ThemeError::UnknownFormat(path.to_path_buf()));
+        };
+        let source = fs::read_to_string(path).map_err(|source| ThemeError::Io {
+            path: path.to_path_buf(),
+            source,
+        })?;
+        parse(&source)
+    }
+
+    /// Parses theme definitions from a TOML string.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ThemeError::Syntax`] for malformed TOML and [`ThemeError::Invalid`] for
+    /// unknown keys or bad values.
+    pub fn from_toml_str(source: &str) -> Result<Self, ThemeError> {
+        let value: toml::Value = toml::from_str(source).map_err(|e| ThemeError::Syntax {
+            format: "TOML",
+            message: e.to_string(),
+        })?;
+        // funnel both formats through one JSON tree, so validation only exists once
+        let value = serde_json::to_value(value).map_err(|e| ThemeError::Syntax {
+            format: "TOML",
+            message: e.to_string(),
+        })?;
+        Self::from_value(&value)
+    }
+
+    /// Parses theme definitions from a JSON string.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ThemeError::Syntax`] for malformed JSON and [`ThemeError::Invalid`] for
+    /// unknown keys or bad values.
+    pub fn from_json_str(source: &str) -> Result<Self, ThemeError> {
+        let value: Value = serde_json::from_str(source).map_err(|e| ThemeError::Syntax {
+            format: "JSON",
+            message: e.to_string(),
+        })?;
+        Self::from_value(&value)
+    }
+
+    /// Returns the theme with the given name, if the file defined one.
+    pub fn get(&self, name: &str) -> Option<&Theme> {
+        self.themes.get(name)
+    }
+
+    /// Returns the names of all themes in the set, in sorted order.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.themes.keys().map(String::as_str)
+    }
+
+    fn from_value(value: &Value) -> Result<Self, ThemeError> {
+        let root = as_table(value, "")?;
+        for key in root.keys() {
+            if key != "themes" && key != "styles" {
+                return Err(invalid(key, "unknown key, expected `themes` or `styles`"));
+            }
+        }
+
+        let mut styles: BTreeMap<String, BoxType> = BTreeMap::new();
+        if let Some(value) = root.get("styles") {
+            for (name, style) in as_table(value, "styles")? {
+                let key = format!("styles.{}", name);
+                styles.insert(name.clone(), parse_style(style, &key)?);
+            }
+        }
+
+        let mut themes = BTreeMap::new();
+        if let Some(value) = root.get("themes") {
+            for (name, theme) in as_table(value, "themes")? {
+                let key = format!("themes.{}", name);
+                themes.insert(name.clone(), parse_theme(theme, &key, &styles)?);
+            }
+        }
+        Ok(ThemeSet { themes })
+    }
+}
+
+/// The error returned when a theme file can't be loaded.
+#[derive(Debug)]
+pub enum ThemeError {
+    /// The theme file could not be read.
+    Io {
+        /// Path of the file that failed to load
+        path: PathBuf,
+        /// The underlying I/O error
+        source: io::Error,
+    },
+    /// The file extension is neither `.toml` nor `.json`.
+    UnknownFormat(PathBuf),
+    /// The file is not valid TOML or JSON.
+    Syntax {
+        /// `"TOML"` or `"JSON"`
+        format: &'static str,
+        /// The parser's message, including the position of the error
+        message: String,
+    },
+    /// A key is unknown or holds a bad value.
+    Invalid {
+        /// Dotted path of the offending key, e.g. `themes.alert.color`
+        key: String,
+        /// What is wrong with it
+        message: String,
+    },
+}
+
+impl ThemeError {
+    /// The dotted path of the offending key, for [`Invalid`](Self::Invalid) errors.
+    pub fn key(&self) -> Option<&str> {
+        match self {
+            ThemeError::Invalid { key, .. } => Some(key),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for ThemeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ThemeError::Io { path, source } => {
+                write!(
+                    f,
+                    "failed to read theme file {}: {}",
+                    path.display(),
+                    source
+                )
+            }
+            ThemeError::UnknownFormat(path) => write!(
+                f,
+                "unsupported theme file {}: expected a .toml or .json extension",
+                path.display()
+            ),
+            ThemeError::Syntax { format, message } => write!(f, "invalid {}: {}", format, message),
+            ThemeError::Invalid { key, message } => write!(f, "{}: {}", key, message),
+        }
+    }
+}
+
+impl std::error::Error for ThemeError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ThemeError::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+fn invalid(key: &str, message: impl Into<String>) -> ThemeError {
+    ThemeError::Invalid {
+        key: if key.is_empty() {
+            "<root>".to_string()
+        } else {
+            key.to_string()
+        },
+        message: message.into(),
+    }
+}
+
+fn as_table<'a>(value: &'a Value, key: &str) -> Result<&'a Map<String, Value>, ThemeError> {
+    value
+        .as_object()
+        .ok_or_else(|| invalid(key, format!("expected a table, found {}", kind(value))))
+}
+
+fn as_str<'a>(value: &'a Value, key: &str) -> Result<&'a str, ThemeError> {
+    value
+        .as_str()
+        .ok_or_else(|| invalid(key, format!("expected a string, found {}", kind(value))))
+}
+
+fn as_usize(value: &Value, key: &str) -> Result<usize, ThemeError> {
+    value.as_u64().map(|n| n as usize).ok_or_else(|| {
+        invalid(
+            key,
+            format!("expected a non-negative integer, found {}", value),
+        )
+    })
+}
+
+fn kind(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "a boolean",
+        Value::Number(_) => "a number",
+        Value::String(_) => "a string",
+        Value::Array(_) => "an array",
+        Value::Object(_) => "a table",
+    }
+}
+
+fn parse_style(value: &Value, key: &str) -> Result<BoxType, ThemeError> {
+    let table = as_table(value, key)?;
+    let base = match table.get("base") {
+        Some(v) => parse_builtin_type(v, &format!("{}.base", key))?,
+        None => BoxType::Single,
+    };
+    let mut glyphs = map_box_type(&base);
+    let mut customized = false;
+    for (name, v) in table {
+        if name == "base" {
+            continue;
+        }
+        let glyph_key = format!("{}.{}", key, name);
+        let glyph = parse_glyph(v, &glyph_key)?;
+        if !glyphs.set_glyph(name, glyph) {
+            return Err(invalid(
+                &glyph_key,
+                "unknown key, expected `base` or a glyph name",
+            ));
+        }
+        customized = true;
+    }
+    Ok(if customized {
+        BoxType::Custom(glyphs)
+    } else {
+        base
+    })
+}
+
+fn parse_theme(
+    value: &Value,
+    key: &str,
+    styles: &BTreeMap<String, BoxType>,
+) -> Result<Theme, ThemeError> {
+    let table = as_table(value, key)?;
+    let mut theme = Theme::default();
+    for (name, v) in table {
+        let field_key = format!("{}.{}", key, name);
+        match name.as_str() {
+            "type" => {
+                let type_name = as_str(v, &field_key)?;
+                if let Some(style) = styles.get(type_name) {
+                    theme.box_type = Some(style.clone());
+                } else {
+                    theme.box_type = Some(parse_builtin_type(v, &field_key)?);
+                }
+            }
+            "color" => theme.color = Some(parse_color(v, &field_key)?),
+            "text_color" => theme.text_color = Some(parse_color(v, &field_key)?),
+            "align" => theme.align = Some(parse_align(v, &field_key)?),
+            "text_align" => theme.text_align = Some(parse_align(v, &field_key)?),
+            "internal_padding" => theme.int_padding = Some(parse_pad(v, &field_key)?),
+            "external_padding" => theme.ext_padding = Some(parse_pad(v, &field_key)?),
+            "glyphs" => {}
+            _ => {
+                return Err(invalid(
+                    &field_key,
+                    format!("unknown key, expected one of {}", THEME_KEYS.join(", ")),
+                ));
+            }
+        }
+    }
+
+    // inline glyphs go on top of whatever the type (or named style) provides
+    if let Some(v) = table.get("glyphs") {
+        let glyph_key = format!("{}.glyphs", key);
+        let mut glyphs = map_box_type(theme.box_type.as_ref().unwrap_or(&BoxType::Single));
+        for (name, g) in as_table(v, &glyph_key)? {
+            let key = format!("{}.{}", glyph_key, name);
+            let glyph = parse_glyph(g, &key)?;
+            if !glyphs.set_glyph(name, glyph) {
+                return Err(invalid(
+                    &key,
+                    format!(
+                        "unknown glyph, expected one of {}, {}",
+                        BorderSet::SLOTS.join(", "),
+                        BorderSet::EDGE_SLOTS.join(", ")
+                    ),
+                ));
+            }
+        }
+        theme.box_type = Some(BoxType::Custom(glyphs));
+    }
+    Ok(theme)
+}
+
+fn parse_builtin_type(value: &Value, key: &str) -> Result<BoxType, ThemeError> {
+    let name = as_str(value, key)?;
+    BoxType::from_name(name).ok_or_else(|| {
+        invalid(
+            key,
+            format!(
+                "unknown border type \"{}\", expected a built-in type or a style name",
+                name
+            ),
+        )
+    })
+}
+
+fn parse_color(value: &Value, key: &str) -> Result<String, ThemeError> {
+    let hex = as_str(value, key)?;
+    match HexColor::parse(hex) {
+        Ok(_) => Ok(hex.to_string()),
+        Err(e) => Err(invalid(
+            key,
+            format!("invalid hex color \"{}\": {}", hex, e),
+        )),
+    }
+}
+
+fn parse_align(value: &Value, key: &str) -> Result<BoxAlign, ThemeError> {
+    let name = as_str(value, key)?;
+    BoxAlign::from_name(name).ok_or_else(|| {
+        invalid(
+            key,
+            format!(
+                "unknown alignment \"{}\", expected left, center or right",
+                name
+            ),
+        )
+    })
+}
+
+fn parse_pad(value: &Value, key: &str) -> Result<BoxPad, ThemeError> {
+    match value {
+        Value::Number(_) => Ok(BoxPad::uniform(as_usize(value, key)?)),
+        Value::Array(items) => {
+            let nums = items
+                .iter()
+                .enumerate()
+                .map(|(i, v)| as_usize(v, &format!("{}[{}]", key, i)))
+                .collect::<Result<Vec<_>, _>>()?;
+            match nums[..] {
+                [vertical, horizontal] => Ok(BoxPad::vh(vertical, horizontal)),
+                [top, left, down, right] => Ok(BoxPad::from_tldr(top, left, down, right)),
+                _ => Err(invalid(
+                    key,
+                    format!(
+                        "expected 2 values [vertical, horizontal] or 4 values [top, left, down, right], found {}",
+                        nums.len()
+                    ),
+                )),
+            }
+        }
+        Value::Object(table) => {
+            let mut pad = BoxPad::new();
+            for (name, v) in table {
+                let side_key = format!("{}.{}", key, name);
+                let n = as_usize(v, &side_key)?;
+                match name.as_str() {
+                    "top" => pad.top = n,
+                    "left" => pad.left = n,
+                    "down" => pad.down = n,
+                    "right" => pad.right = n,
+                    _ => {
+                        return Err(invalid(
+                            &side_key,
+                            "unknown side, expected top, left, down or right",
+                        ));
+                    }
+                }
+            }
+            Ok(pad)
+        }
+        _ => Err(invalid(
+            key,
+            format!(
+                "expected a number, an array or a table, found {}",
+                kind(value)
+            ),
+        )),
+    }
+}
+
+fn parse_glyph(value: &Value, key: &str) -> Result<char, ThemeError> {
+    let s = as_str(value, key)?;
+    let mut chars = s.chars();
+    let glyph = match (chars.next(), chars.next()) {
+        (Some(c), None) => c,
+        _ => {
+            return Err(invalid(
+                key,
+                format!("expected exactly one character, found \"{}\"", s),
+            ));
+        }
+    };
+    // the slot name is only used for the message, which we re-key anyway
+    BorderSet::check_glyph("glyph", glyph).map_err(|e| match e {
+        BorderSetError::Width { width, .. } => invalid(
+            key,
+            format!(
+                "glyph \"{}\" is {} columns wide, border glyphs must be exactly 1",
+                glyph, width
+            ),
+        ),
+        other => invalid(key, other.to_string()),
+    })?;
+    Ok(glyph)
+}
@@ -0,0 +1,287 @@
+#![cfg(feature = "cli")]
+
+use std::io::Write;
+use std::process::{Command, Output, Stdio};
+
+fn boxy(args: &[&str], stdin: &str) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_boxy"))
+        .args(args)
+        .env("NO_COLOR", "1")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .expect("failed to start boxy");
+    // the input isn't read when the text comes from flags
+    let _ = child.stdin.take().unwrap().write_all(stdin.as_bytes());
+    child.wait_with_output().unwrap()
+}
+
+fn stdout(output: &Output) -> String {
+    assert!(
+        output.status.success(),
+        "boxy failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    String::from_utf8(output.stdout.clone()).unwrap()
+}
+
+#[test]
+fn stdin_with_title() {
+    let out = stdout(&boxy(
+        &[
+            "--type", "rounded", "--color", "#00ff00", "--title", "Deploy", "-w", "20",
+        ],
+        "Deploy done\n",
+    ));
+    let lines: Vec<&str> = out.lines().collect();
+    assert_eq!(lines.len(), 5);
+    assert!(lines[0].starts_with('╭') && lines[0].ends_with('╮'));
+    assert_eq!(lines[1].trim_matches('│').trim(), "Deploy");
+    assert!(lines[2].starts_with('├'));
+    assert_eq!(lines[3], "│ Deploy done      │");
+    assert!(lines[4].starts_with('╰'));
+}
+
+#[test]
+fn stdin_separator_splits_segments() {
+    let out = stdout(&boxy(&["-w", "12"], "one\n---\ntwo\r\n---\n"));
+    assert_eq!(
+        out,
+        "┌──────────┐\n│ one      │\n├──────────┤\n│ two      │\n└──────────┘\n"
+    );
+}
+
+#[test]
+fn repeated_segment_flags() {
+    let out = stdout(&boxy(
+        &["-s", "one", "--segment", "two", "-t", "ascii", "-w", "12"],
+        "ignored\n",
+    ));
+    assert_eq!(
+        out,
+        "+----------+\n| one      |\n+----------+\n| two      |\n+----------+\n"
+    );
+}
+
+#[test]
+fn alignment_padding_and_margin() {
+    let out = stdout(&boxy(
+        &[
+            "-s",
+            "hi",
+            "--text-align",
+            "right",
+            "-p",
+            "0,3",
+            "-m",
+            "2",
+            "-w",
+            "12",
+            "--term-width",
+            "40",
+        ],
+        "",
+    ));
+    let line = out.lines().nth(1).unwrap();
+    assert!(line.starts_with(' '));
+    assert!(line.trim_start().starts_with("│     hi   │"));
+
+    let out = stdout(&boxy(
+        &[
+            "-s",
+            "hi",
+            "--align",
+            "right",
+            "-w",
+            "10",
+            "--term-width",
+            "30",
+        ],
+        "",
+    ));
+    assert!(out.lines().all(|line| line.starts_with(&" ".repeat(19))));
+}
+
+#[test]
+fn rejects_bad_input() {
+    let output = boxy(&["--color", "green", "-s", "x"], "");
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("--color"));
+
+    let output = boxy(&["--padding", "1,2,3", "-s", "x"], "");
+    assert!(!output.status.success());
+
+    let output = boxy(&[], "");
+    assert_eq!(output.status.code(), Some(2));
+
+    // too narrow for the borders and a column of text: an error, not a panic
+    let output = boxy(&["-s", "hi", "--width", "3", "--term-width", "40"], "");
+    assert_eq!(output.status.code(), Some(2));
+    assert!(
+        String::from_utf8_lossy(&output.stderr).starts_with("boxy: width 3 is too narrow"),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
+
+const LAYOUT: &str = r##"type = "ascii"
+
+[[segments]]
+content = { single = ["Nightly"] }
+align = "center"
+
+[[segments]]
+content = { columnar = [["unit"], ["ok"]] }
+colors = { columnar = [["#ffffff"], ["#00ff00"]] }
+ratios = [2, 1]
+"##;
+
+fn stderr(output: &Output) -> String {
+    assert!(!output.status.success());
+    String::from_utf8(output.stderr.clone()).unwrap()
+}
+
+#[test]
+fn render_toml_file() {
+    let path = std::env::temp_dir().join(format!("boxy-cli-test-{}.toml", std::process::id()));
+    std::fs::write(&path, LAYOUT).unwrap();
+    let output = boxy(
+        &["render", path.to_str().unwrap(), "--term-width", "20"],
+        "",
+    );
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(
+        stdout(&output),
+        "+------------------+\n\
+         |     Nightly      |\n\
+         +-----------+------+\n\
+         | unit      | ok   |\n\
+         +-----------+------+\n"
+    );
+}
+
+#[test]
+fn render_json_from_stdin() {
+    let json = r##"{"type": "ascii", "width": 12, "segments": [{"content": {"single": ["hi"]}}]}"##;
+    let out = stdout(&boxy(&["render", "-", "--term-width", "12"], json));
+    assert_eq!(out, "+----------+\n|    hi    |\n+----------+\n");
+
+    let out = stdout(&boxy(
+        &["render", "--format", "toml", "-", "--term-width", "20"],
+        LAYOUT,
+    ));
+    assert_eq!(out.lines().count(), 5);
+}
+
+#[test]
+fn render_errors_point_at_the_value() {
+    let bad_color = LAYOUT.replace("#00ff00", "#nope");
+    let err = stderr(&boxy(&["render", "--format", "toml", "-"], &bad_color));
+    assert!(
+        err.starts_with("boxy: <stdin>:9:38: segments[1].colors[1][0]: invalid hex color"),
+        "{}",
+        err
+    );
+
+    let bad_ratios = LAYOUT.replace("[2, 1]", "[2]");
+    let err = stderr(&boxy(&["render", "--format", "toml", "-"], &bad_ratios));
+    assert!(
+        err.starts_with("boxy: <stdin>:10:10: segments[1].ratios:"),
+        "{}",
+        err
+    );
+
+    let json = "{\n  \"segments\": [\n    {\"content\": {\"single\": [\"a\"]},\n     \"colors\": {\"single\": [\"red\"]}}\n  ]\n}";
+    let err = stderr(&boxy(&["render", "-"], json));
+    assert!(
+        err.starts_with("boxy: <stdin>:4:28: segments[0].colors[0]:"),
+        "{}",
+        err
+    );
+
+    let err = stderr(&boxy(&["render", "-"], "{\n  \"colour\": \"#ffffff\"\n}"));
+    assert!(
+        err.starts_with("boxy: <stdin>:2:10: unknown field `colour`"),
+        "{}",
+        err
+    );
+
+    let err = stderr(&boxy(
+        &["render", "--format", "toml", "-"],
+        "type = \"round\"\n",
+    ));
+    assert!(
+        err.starts_with("boxy: <stdin>:1:8: unknown variant `round`"),
+        "{}",
+        err
+    );
+}
+
+#[cfg(unix)]
+#[test]
+fn run_boxes_output_and_passes_exit_code() {
+    let output = boxy(
+        &[
+            "run",
+            "-t",
+            "ascii",
+            "--term-width",
+            "30",
+            "--",
+            "sh",
+            "-c",
+            "echo hi; exit 3",
+        ],
+        "",
+    );
+    assert_eq!(output.status.code(), Some(3));
+    let out = String::from_utf8(output.stdout).unwrap();
+    let lines: Vec<&str> = out.lines().collect();
+    assert_eq!(lines[1], "| $ sh -c 'echo hi; exit 3'  |");
+    assert_eq!(lines[3], "| hi                         |");
+    assert!(lines[5].contains(" exit 3 after "));
+    assert_eq!(lines.len(), 7);
+
+    let output = boxy(&["run", "--", "true"], "");
+    assert_eq!(output.status.code(), Some(0));
+
+    let output = boxy(&["run", "--", "boxy-test-no-such-command"], "");
+    assert_eq!(output.status.code(), Some(127));
+
+    // the width is checked before the command runs
+    let output = boxy(&["run", "--width", "3", "--", "sh", "-c", "echo ran"], "");
+    assert_eq!(output.status.code(), Some(2));
+    assert!(output.stdout.is_empty());
+    assert!(String::from_utf8_lossy(&output.stderr).starts_with("boxy: width 3 is too narrow"));
+}
+
+#[cfg(unix)]
+#[test]
+fn run_split_and_tail() {
+    let out = stdout(&boxy(
+        &[
+            "run",
+            "--split",
+            "--tail",
+            "2",
+            "--term-width",
+            "40",
+            "--",
+            "sh",
+            "-c",
+            "seq 1 5; echo oops >&2",
+        ],
+        "",
+    ));
+    let lines: Vec<&str> = out.lines().collect();
+    assert!(lines[2].contains(" stdout "));
+    assert_eq!(
+        lines[3].trim_matches(['│', ' ']),
+        "... 3 earlier lines hidden"
+    );
+    assert_eq!(lines[4].trim_matches(['│', ' ']), "4");
+    assert_eq!(lines[5].trim_matches(['│', ' ']), "5");
+    assert!(lines[6].contains(" stderr "));
+    assert_eq!(lines[7].trim_matches(['│', ' ']), "oops");
+}